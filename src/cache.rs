@@ -0,0 +1,352 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+    time::{Duration, Instant},
+};
+
+use dashmap::DashMap;
+
+use crate::model::user::Username;
+
+/// Storage of username-userid pairs so that fetching data by username does
+/// one instead of two requests.
+///
+/// Usernames are always passed in lowercase.
+///
+/// Implementations are called synchronously while creating and completing
+/// requests so they should not block. To share the cache with a persistent
+/// backend, consider mirroring it locally and syncing it in the background.
+///
+/// Defaults to [`UnboundedUserIdCache`] and can be set through
+/// [`OsuBuilder::user_id_cache`](crate::OsuBuilder::user_id_cache).
+pub trait UserIdCache: Send + Sync + 'static {
+    /// Get the user id for the given name.
+    fn get(&self, name: &str) -> Option<u32>;
+
+    /// Map the given name to the given user id, overwriting previous entries.
+    fn insert(&self, name: Username, user_id: u32);
+
+    /// Remove the given name.
+    fn remove(&self, name: &str);
+
+    /// Remove the given name only if it's mapped to the given user id.
+    ///
+    /// The default implementation is not atomic; it checks through
+    /// [`get`](UserIdCache::get) before calling
+    /// [`remove`](UserIdCache::remove).
+    fn remove_if(&self, name: &str, user_id: u32) {
+        if self.get(name) == Some(user_id) {
+            self.remove(name);
+        }
+    }
+}
+
+impl<T: UserIdCache + ?Sized> UserIdCache for Box<T> {
+    fn get(&self, name: &str) -> Option<u32> {
+        (**self).get(name)
+    }
+
+    fn insert(&self, name: Username, user_id: u32) {
+        (**self).insert(name, user_id);
+    }
+
+    fn remove(&self, name: &str) {
+        (**self).remove(name);
+    }
+
+    fn remove_if(&self, name: &str, user_id: u32) {
+        (**self).remove_if(name, user_id);
+    }
+}
+
+impl<T: UserIdCache + ?Sized> UserIdCache for Arc<T> {
+    fn get(&self, name: &str) -> Option<u32> {
+        (**self).get(name)
+    }
+
+    fn insert(&self, name: Username, user_id: u32) {
+        (**self).insert(name, user_id);
+    }
+
+    fn remove(&self, name: &str) {
+        (**self).remove(name);
+    }
+
+    fn remove_if(&self, name: &str, user_id: u32) {
+        (**self).remove_if(name, user_id);
+    }
+}
+
+/// [`UserIdCache`] that keeps all entries forever.
+#[derive(Default)]
+pub struct UnboundedUserIdCache {
+    inner: DashMap<Username, u32>,
+}
+
+impl UnboundedUserIdCache {
+    /// Create a new empty [`UnboundedUserIdCache`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The amount of stored entries.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Whether no entries are stored.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl UserIdCache for UnboundedUserIdCache {
+    fn get(&self, name: &str) -> Option<u32> {
+        self.inner.get(name).map(|entry| *entry)
+    }
+
+    fn insert(&self, name: Username, user_id: u32) {
+        self.inner.insert(name, user_id);
+    }
+
+    fn remove(&self, name: &str) {
+        self.inner.remove(name);
+    }
+
+    fn remove_if(&self, name: &str, user_id: u32) {
+        self.inner.remove_if(name, |_, id| *id == user_id);
+    }
+}
+
+/// [`UserIdCache`] that keeps at most a given amount of entries and evicts
+/// the least recently used ones first.
+pub struct LruUserIdCache {
+    capacity: usize,
+    state: Mutex<LruState>,
+}
+
+#[derive(Default)]
+struct LruState {
+    entries: HashMap<Username, (u32, u64)>,
+    /// Names by the time they were last used; the first is the least
+    /// recently used.
+    order: BTreeMap<u64, Username>,
+    tick: u64,
+}
+
+impl LruState {
+    const fn next_tick(&mut self) -> u64 {
+        let tick = self.tick;
+        self.tick += 1;
+
+        tick
+    }
+}
+
+impl LruUserIdCache {
+    /// Create a new [`LruUserIdCache`] that keeps at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(LruState::default()),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, LruState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// The amount of stored entries.
+    pub fn len(&self) -> usize {
+        self.state().entries.len()
+    }
+
+    /// Whether no entries are stored.
+    pub fn is_empty(&self) -> bool {
+        self.state().entries.is_empty()
+    }
+}
+
+impl UserIdCache for LruUserIdCache {
+    fn get(&self, name: &str) -> Option<u32> {
+        let mut state = self.state();
+        let tick = state.next_tick();
+        let (user_id, last_used) = state.entries.get_mut(name)?;
+        let user_id = *user_id;
+        let prev = std::mem::replace(last_used, tick);
+
+        if let Some(name) = state.order.remove(&prev) {
+            state.order.insert(tick, name);
+        }
+
+        Some(user_id)
+    }
+
+    fn insert(&self, name: Username, user_id: u32) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut state = self.state();
+        let tick = state.next_tick();
+
+        if let Some((_, prev)) = state.entries.insert(name.clone(), (user_id, tick)) {
+            state.order.remove(&prev);
+        } else if state.entries.len() > self.capacity {
+            if let Some((_, oldest)) = state.order.pop_first() {
+                state.entries.remove(&oldest);
+            }
+        }
+
+        state.order.insert(tick, name);
+    }
+
+    fn remove(&self, name: &str) {
+        let mut state = self.state();
+
+        if let Some((_, last_used)) = state.entries.remove(name) {
+            state.order.remove(&last_used);
+        }
+    }
+
+    fn remove_if(&self, name: &str, user_id: u32) {
+        let mut state = self.state();
+
+        let Some(&(id, last_used)) = state.entries.get(name) else {
+            return;
+        };
+
+        if id == user_id {
+            state.entries.remove(name);
+            state.order.remove(&last_used);
+        }
+    }
+}
+
+/// Purge expired entries of a [`TtlUserIdCache`] every this many inserts.
+const TTL_PURGE_INTERVAL: usize = 1024;
+
+/// [`UserIdCache`] whose entries expire after a given duration.
+pub struct TtlUserIdCache {
+    ttl: Duration,
+    inner: DashMap<Username, (u32, Instant)>,
+    inserts: AtomicUsize,
+}
+
+impl TtlUserIdCache {
+    /// Create a new [`TtlUserIdCache`] whose entries expire after `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            inner: DashMap::new(),
+            inserts: AtomicUsize::new(0),
+        }
+    }
+
+    /// The amount of stored entries, including expired ones that were not
+    /// purged yet.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Whether no entries are stored.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Remove all expired entries.
+    pub fn purge(&self) {
+        let now = Instant::now();
+        self.inner.retain(|_, (_, expires_at)| *expires_at > now);
+    }
+}
+
+impl UserIdCache for TtlUserIdCache {
+    fn get(&self, name: &str) -> Option<u32> {
+        let now = Instant::now();
+
+        let user_id = {
+            let entry = self.inner.get(name)?;
+            let (user_id, expires_at) = *entry;
+
+            (expires_at > now).then_some(user_id)
+        };
+
+        if user_id.is_none() {
+            self.inner
+                .remove_if(name, |_, (_, expires_at)| *expires_at <= now);
+        }
+
+        user_id
+    }
+
+    fn insert(&self, name: Username, user_id: u32) {
+        self.inner
+            .insert(name, (user_id, Instant::now() + self.ttl));
+
+        if self.inserts.fetch_add(1, Ordering::Relaxed) % TTL_PURGE_INTERVAL
+            == TTL_PURGE_INTERVAL - 1
+        {
+            self.purge();
+        }
+    }
+
+    fn remove(&self, name: &str) {
+        self.inner.remove(name);
+    }
+
+    fn remove_if(&self, name: &str, user_id: u32) {
+        self.inner.remove_if(name, |_, (id, _)| *id == user_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lru() {
+        let cache = LruUserIdCache::new(2);
+
+        cache.insert("a".into(), 1);
+        cache.insert("b".into(), 2);
+
+        // Use "a" so that "b" becomes the least recently used
+        assert_eq!(cache.get("a"), Some(1));
+        cache.insert("c".into(), 3);
+
+        assert_eq!(cache.get("a"), Some(1));
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.get("c"), Some(3));
+
+        // Overwriting does not evict
+        cache.insert("c".into(), 4);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get("c"), Some(4));
+
+        cache.remove_if("c", 3);
+        assert_eq!(cache.get("c"), Some(4));
+
+        cache.remove("a");
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.len(), 1);
+
+        cache.remove_if("c", 4);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn ttl() {
+        let cache = TtlUserIdCache::new(Duration::ZERO);
+        cache.insert("a".into(), 1);
+        assert_eq!(cache.get("a"), None);
+        assert!(cache.is_empty());
+
+        let cache = TtlUserIdCache::new(Duration::from_mins(1));
+        cache.insert("a".into(), 1);
+        assert_eq!(cache.get("a"), Some(1));
+    }
+}
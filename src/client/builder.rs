@@ -1,14 +1,20 @@
 use super::{
+    ratelimit::{AdaptiveRatelimit, PriorityGate},
+    response_cache::{CachedResponses, ResponseCache},
     token::{AuthorizationBuilder, CurrentToken},
-    Authorization, AuthorizationKind, Osu, OsuInner, Scopes, Token,
+    Authorization, AuthorizationKind, Osu, OsuInner, Priority, RetryPolicy, Scopes, Token,
+};
+use crate::{
+    error::OsuError,
+    middleware::Middleware,
+    transport::{HyperTransport, Transport},
+    OsuResult,
 };
-use crate::{error::OsuError, OsuResult};
 
-use hyper_rustls::HttpsConnectorBuilder;
-use hyper_util::{client::legacy::connect::HttpConnector, rt::TokioExecutor};
 use leaky_bucket::RateLimiter;
 use std::{sync::Arc, time::Duration};
 use tokio::sync::oneshot;
+use url::Url;
 
 /// Builder struct for an [`Osu`](crate::Osu) client.
 ///
@@ -20,9 +26,18 @@ pub struct OsuBuilder {
     auth: Option<AuthorizationBuilder>,
     client_id: Option<u64>,
     client_secret: Option<String>,
-    retries: u8,
+    retry: RetryPolicy,
     timeout: Duration,
     per_second: u32,
+    api_url: Option<String>,
+    oauth_url: Option<String>,
+    transport: Option<Box<dyn Transport>>,
+    middlewares: Vec<Box<dyn Middleware>>,
+    response_cache: Option<ResponseCache>,
+    #[cfg(feature = "cache")]
+    user_id_cache: Option<Box<dyn crate::cache::UserIdCache>>,
+    #[cfg(feature = "cache")]
+    reverify_cached_names: bool,
 }
 
 impl Default for OsuBuilder {
@@ -31,9 +46,18 @@ impl Default for OsuBuilder {
             auth: None,
             client_id: None,
             client_secret: None,
-            retries: 2,
+            retry: RetryPolicy::default(),
             timeout: Duration::from_secs(10),
             per_second: 15,
+            api_url: None,
+            oauth_url: None,
+            transport: None,
+            middlewares: Vec::new(),
+            response_cache: None,
+            #[cfg(feature = "cache")]
+            user_id_cache: None,
+            #[cfg(feature = "cache")]
+            reverify_cached_names: false,
         }
     }
 }
@@ -56,24 +80,20 @@ impl OsuBuilder {
     ///   - client id was not set
     ///   - client secret was not set
     ///   - API did not provide a token for the given client id and client secret
-    ///   - native roots are missing to build the https connector
+    ///   - no transport was set and native roots are missing to build the
+    ///     https connector
+    ///   - the API or OAuth base URL is invalid
     pub async fn build(self) -> OsuResult<Osu> {
         let client_id = self.client_id.ok_or(OsuError::BuilderMissingId)?;
         let client_secret = self.client_secret.ok_or(OsuError::BuilderMissingSecret)?;
 
-        let mut http = HttpConnector::new();
-        http.enforce_http(false);
-
-        let connector = HttpsConnectorBuilder::new()
-            .with_native_roots()
-            .map_err(|source| OsuError::ConnectorRoots { source })?
-            .https_or_http()
-            .enable_http1()
-            .enable_http2()
-            .wrap_connector(http);
+        let api_url = base_url(self.api_url, DEFAULT_API_URL)?;
+        let oauth_url = base_url(self.oauth_url, DEFAULT_OAUTH_URL)?;
 
-        let http =
-            hyper_util::client::legacy::Client::builder(TokioExecutor::new()).build(connector);
+        let http = match self.transport {
+            Some(transport) => transport,
+            None => Box::new(HyperTransport::new()?),
+        };
 
         let ratelimiter = RateLimiter::builder()
             .max(self.per_second as usize)
@@ -85,13 +105,23 @@ impl OsuBuilder {
         let inner = Arc::new(OsuInner {
             client_id,
             client_secret: client_secret.into_boxed_str(),
+            api_url,
+            oauth_url,
             http,
             ratelimiter: Arc::new(ratelimiter),
+            adaptive_ratelimit: AdaptiveRatelimit::new(),
+            priority_gate: PriorityGate::new(),
             timeout: self.timeout,
             token: CurrentToken::new(),
-            retries: self.retries,
+            retry: self.retry,
+            middlewares: self.middlewares.into_boxed_slice(),
+            response_cache: self.response_cache.map(CachedResponses::new),
+            #[cfg(feature = "cache")]
+            cache: self
+                .user_id_cache
+                .unwrap_or_else(|| Box::new(crate::cache::UnboundedUserIdCache::new())),
             #[cfg(feature = "cache")]
-            cache: dashmap::DashMap::new(),
+            reverify_cached_names: self.reverify_cached_names,
         });
 
         #[cfg(feature = "metrics")]
@@ -104,10 +134,14 @@ impl OsuBuilder {
                 redirect_uri,
                 scopes,
             }) => {
-                let auth_kind =
-                    AuthorizationBuilder::perform_local_oauth(redirect_uri, client_id, scopes)
-                        .await
-                        .map(AuthorizationKind::User)?;
+                let auth_kind = AuthorizationBuilder::perform_local_oauth(
+                    &inner.oauth_url,
+                    redirect_uri,
+                    client_id,
+                    scopes,
+                )
+                .await
+                .map(AuthorizationKind::User)?;
 
                 build_with_refresh(inner, auth_kind).await
             }
@@ -127,6 +161,7 @@ impl OsuBuilder {
                 Ok(Osu {
                     inner,
                     token_loop_tx: Some(tx),
+                    priority: Priority::default(),
                 })
             }
             Some(AuthorizationBuilder::Given { token, .. }) => {
@@ -135,6 +170,7 @@ impl OsuBuilder {
                 Ok(Osu {
                     inner,
                     token_loop_tx: None,
+                    priority: Priority::default(),
                 })
             }
             None => build_with_refresh(inner, AuthorizationKind::default()).await,
@@ -230,9 +266,23 @@ impl OsuBuilder {
         self
     }
 
-    /// In case the request times out, retry up to this many times, defaults to 2.
+    /// In case the request times out or fails with a retryable status, retry
+    /// up to this many times, defaults to 2.
+    ///
+    /// Shorthand for adjusting [`RetryPolicy::max_retries`] of the current
+    /// retry policy.
     pub const fn retries(mut self, retries: u8) -> Self {
-        self.retries = retries;
+        self.retry.max_retries = retries;
+
+        self
+    }
+
+    /// Set the [`RetryPolicy`] that determines when and how failed requests
+    /// are retried.
+    ///
+    /// See [`RetryPolicy::default`] for the default policy.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
 
         self
     }
@@ -249,12 +299,120 @@ impl OsuBuilder {
     ///
     /// Check out the osu!api's [terms of use] for acceptable values.
     ///
+    /// Independent of this value, the client slows down on its own when the
+    /// osu!api reports that the request budget is running low, see
+    /// [`Osu::ratelimit_budget`].
+    ///
     /// [terms of use]: https://osu.ppy.sh/docs/index.html#terms-of-use
     pub fn ratelimit(mut self, reqs_per_sec: u32) -> Self {
         self.per_second = reqs_per_sec.clamp(1, 20);
 
         self
     }
+
+    /// Set the base URL that all API requests are sent to, defaults to
+    /// `https://osu.ppy.sh/api/v2/`.
+    ///
+    /// Endpoint paths such as `users/{user_id}` are appended to this URL so
+    /// it may point to e.g. a caching proxy or a development instance of
+    /// osu-web.
+    pub fn api_base_url(mut self, url: impl Into<String>) -> Self {
+        self.api_url = Some(url.into());
+
+        self
+    }
+
+    /// Use the given [`Transport`] to send requests instead of the
+    /// default [`HyperTransport`].
+    pub fn transport(mut self, transport: impl Transport) -> Self {
+        self.transport = Some(Box::new(transport));
+
+        self
+    }
+
+    /// Add a [`Middleware`] to the chain of middlewares that can inspect and
+    /// modify requests and responses.
+    ///
+    /// Requests are passed through the middlewares in the order they were
+    /// added, responses in reverse order.
+    pub fn middleware(mut self, middleware: impl Middleware) -> Self {
+        self.middlewares.push(Box::new(middleware));
+
+        self
+    }
+
+    /// Cache responses in memory according to the given [`ResponseCache`].
+    ///
+    /// By default, responses are not cached.
+    pub fn response_cache(mut self, cache: ResponseCache) -> Self {
+        self.response_cache = Some(cache);
+
+        self
+    }
+
+    /// Store username-userid pairs in the given [`UserIdCache`], defaults to
+    /// an [`UnboundedUserIdCache`].
+    ///
+    /// [`UserIdCache`]: crate::cache::UserIdCache
+    /// [`UnboundedUserIdCache`]: crate::cache::UnboundedUserIdCache
+    #[cfg(feature = "cache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cache")))]
+    pub fn user_id_cache(mut self, cache: impl crate::cache::UserIdCache) -> Self {
+        self.user_id_cache = Some(Box::new(cache));
+
+        self
+    }
+
+    /// Whether a request for a username that was resolved through the
+    /// username cache should fetch the user again if it fails with a 404,
+    /// defaults to `false`.
+    ///
+    /// Since a cached name might belong to a user that was renamed or
+    /// restricted in the meanwhile, this ensures that a 404 is only returned
+    /// if the name indeed does not belong to an available user anymore. The
+    /// request is only sent again if the name now belongs to a different
+    /// user id.
+    #[cfg(feature = "cache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cache")))]
+    pub const fn reverify_cached_names(mut self, reverify: bool) -> Self {
+        self.reverify_cached_names = reverify;
+
+        self
+    }
+
+    /// Set the base URL for OAuth requests, defaults to
+    /// `https://osu.ppy.sh/oauth/`.
+    ///
+    /// Tokens will be requested from `{url}token` and, when using
+    /// [`with_local_authorization`], the user will be asked to authorize
+    /// through `{url}authorize`.
+    ///
+    /// [`with_local_authorization`]: OsuBuilder::with_local_authorization
+    pub fn oauth_base_url(mut self, url: impl Into<String>) -> Self {
+        self.oauth_url = Some(url.into());
+
+        self
+    }
+}
+
+const DEFAULT_API_URL: &str = "https://osu.ppy.sh/api/v2/";
+const DEFAULT_OAUTH_URL: &str = "https://osu.ppy.sh/oauth/";
+
+/// Validates the given base URL and ensures it ends with a slash so that
+/// paths can be appended to it.
+fn base_url(url: Option<String>, default: &str) -> OsuResult<Box<str>> {
+    let Some(mut url) = url else {
+        return Ok(Box::from(default));
+    };
+
+    if !url.ends_with('/') {
+        url.push('/');
+    }
+
+    match Url::parse(&url) {
+        Ok(_) => Ok(url.into_boxed_str()),
+        Err(source) => Err(OsuError::Url { source, url }),
+    }
 }
 
 async fn build_with_refresh(inner: Arc<OsuInner>, auth_kind: AuthorizationKind) -> OsuResult<Osu> {
@@ -276,5 +434,6 @@ async fn build_with_refresh(inner: Arc<OsuInner>, auth_kind: AuthorizationKind)
     Ok(Osu {
         inner,
         token_loop_tx: Some(tx),
+        priority: Priority::default(),
     })
 }
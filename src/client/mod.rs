@@ -1,25 +1,37 @@
 mod builder;
+mod ratelimit;
+mod response_cache;
+mod retry;
 mod scopes;
 mod token;
 
-use bytes::Bytes;
-use http_body_util::Full;
-use hyper_util::client::legacy::{connect::HttpConnector, Client as HyperClient};
+pub(crate) use self::response_cache::{CacheSlot, CachedResponses, Lookup, Validators};
 
-pub use self::{builder::OsuBuilder, scopes::Scopes, token::Token};
+pub use self::{
+    builder::OsuBuilder,
+    ratelimit::{Priority, RatelimitBudget},
+    response_cache::ResponseCache,
+    retry::RetryPolicy,
+    scopes::Scopes,
+    token::Token,
+};
 
 pub(crate) use self::token::{Authorization, TokenResponse};
 
-use self::token::{AuthorizationKind, CurrentToken};
+use self::{
+    ratelimit::{AdaptiveRatelimit, PriorityGate},
+    token::{AuthorizationKind, CurrentToken},
+};
 
 #[allow(clippy::wildcard_imports)]
 use crate::{
+    middleware::Middleware,
     model::{user::UserBeatmapsetsKind, GameMode},
     request::*,
+    transport::Transport,
     OsuResult,
 };
 
-use hyper_rustls::HttpsConnector;
 use leaky_bucket::RateLimiter;
 use std::{ops::Drop, sync::Arc, time::Duration};
 use tokio::sync::oneshot::Sender;
@@ -28,6 +40,7 @@ use tokio::sync::oneshot::Sender;
 pub struct Osu {
     pub(crate) inner: Arc<OsuInner>,
     token_loop_tx: Option<Sender<()>>,
+    pub(crate) priority: Priority,
 }
 
 impl Osu {
@@ -54,6 +67,59 @@ impl Osu {
         self.inner.token.get(Token::to_owned)
     }
 
+    /// Return a handle to this client whose requests have the given
+    /// [`Priority`] when waiting for the ratelimiter.
+    ///
+    /// The handle shares everything with this client, including the
+    /// ratelimiter and the token. Note that only the original client keeps
+    /// the token up to date so it must outlive the handle.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// # use rosu_v2::prelude::*;
+    /// # let _ = async {
+    /// # let osu: Osu = unimplemented!();
+    /// let background = osu.with_priority(Priority::Low);
+    ///
+    /// // Interactive requests will be sent before this one
+    /// let user = background.user("peppy").await?;
+    /// # Ok::<_, OsuError>(()) };
+    /// ```
+    #[must_use]
+    pub fn with_priority(&self, priority: Priority) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            token_loop_tx: None,
+            priority,
+        }
+    }
+
+    /// Return the request budget as last reported by the osu!api.
+    ///
+    /// This can be used to postpone low-priority work before the client gets
+    /// throttled. Returns `None` if the osu!api has not yet provided any
+    /// ratelimit headers.
+    #[inline]
+    pub fn ratelimit_budget(&self) -> Option<RatelimitBudget> {
+        self.inner.adaptive_ratelimit.budget()
+    }
+
+    /// Remove all responses from the [`ResponseCache`], if one was configured
+    /// through [`OsuBuilder::response_cache`].
+    pub fn clear_response_cache(&self) {
+        if let Some(ref cache) = self.inner.response_cache {
+            cache.clear();
+        }
+    }
+
+    /// Create a [`Batch`] to execute many requests, possibly of different
+    /// types, with a limited amount of concurrency.
+    #[inline]
+    pub const fn batch<'a, T: Send + 'a>(&'a self) -> Batch<'a, T> {
+        Batch::new()
+    }
+
     /// Get a [`BeatmapExtended`](crate::model::beatmap::BeatmapExtended).
     ///
     /// Filled options will be: `deleted_at` (if deleted), `fail_times`,
@@ -71,6 +137,9 @@ impl Osu {
     ///
     /// The contained maps will have these options filled: `mapset`,
     /// `fail_times`, and `max_combo` (if available for mode).
+    ///
+    /// All ids after the 50th are ignored; use
+    /// [`beatmaps_bulk`](Osu::beatmaps_bulk) for more ids.
     #[inline]
     pub fn beatmaps<I>(&self, map_ids: I) -> GetBeatmaps<'_>
     where
@@ -79,6 +148,27 @@ impl Osu {
         GetBeatmaps::new(self, map_ids)
     }
 
+    /// Get a [`BulkLookup`](crate::model::BulkLookup) of
+    /// [`Beatmap`](crate::model::beatmap::Beatmap)s for any amount of ids.
+    ///
+    /// The ids are requested concurrently in batches of 50. The found maps
+    /// are in the order of the given ids and ids for which no map was found
+    /// are listed separately. Duplicate ids are only requested and listed
+    /// once.
+    ///
+    /// If any batch fails, the whole lookup fails with that error and the
+    /// results of the other batches are discarded.
+    ///
+    /// The contained maps will have these options filled: `mapset`,
+    /// `fail_times`, and `max_combo` (if available for mode).
+    #[inline]
+    pub fn beatmaps_bulk<I>(&self, map_ids: I) -> GetBeatmapsBulk<'_>
+    where
+        I: IntoIterator<Item = u32>,
+    {
+        GetBeatmapsBulk::new(self, map_ids)
+    }
+
     /// Get [`BeatmapScores`](crate::model::beatmap::BeatmapScores).
     ///
     /// The contained scores will have the following options filled:
@@ -100,6 +190,23 @@ impl Osu {
         GetBeatmapDifficultyAttributes::new(self, map_id)
     }
 
+    /// Get a [`BeatmapPack`](crate::model::beatmap::BeatmapPack) including
+    /// its mapsets through the pack's tag, e.g. `S1` or `T42`.
+    ///
+    /// If the client was authorized through a user, the pack will contain
+    /// the user's completion.
+    #[inline]
+    pub fn beatmap_pack(&self, tag: impl Into<Box<str>>) -> GetBeatmapPack<'_> {
+        GetBeatmapPack::new(self, tag)
+    }
+
+    /// Get [`BeatmapPacks`](crate::model::beatmap::BeatmapPacks) of a given
+    /// type; standard packs by default.
+    #[inline]
+    pub const fn beatmap_packs(&self) -> GetBeatmapPacks<'_> {
+        GetBeatmapPacks::new(self)
+    }
+
     /// Get a [`BeatmapUserScore`](crate::model::score::BeatmapUserScore).
     ///
     /// The contained [`Score`](crate::model::score::Score) will have the
@@ -142,6 +249,28 @@ impl Osu {
         GetBeatmapset::new(self, mapset_id)
     }
 
+    /// Get [`BeatmapsetDiscussions`](crate::model::beatmap::BeatmapsetDiscussions),
+    /// optionally filtered by map, mapset, user, message types, or resolved
+    /// state.
+    #[inline]
+    pub fn beatmapset_discussions(&self) -> GetBeatmapsetDiscussions<'_> {
+        GetBeatmapsetDiscussions::new(self)
+    }
+
+    /// Get [`BeatmapsetDiscussionPosts`](crate::model::beatmap::BeatmapsetDiscussionPosts),
+    /// optionally filtered by discussion, user, or post types.
+    #[inline]
+    pub fn beatmapset_discussion_posts(&self) -> GetBeatmapsetDiscussionPosts<'_> {
+        GetBeatmapsetDiscussionPosts::new(self)
+    }
+
+    /// Get [`BeatmapsetDiscussionVotes`](crate::model::beatmap::BeatmapsetDiscussionVotes),
+    /// optionally filtered by discussion, voter, receiver, or score.
+    #[inline]
+    pub fn beatmapset_discussion_votes(&self) -> GetBeatmapsetDiscussionVotes<'_> {
+        GetBeatmapsetDiscussionVotes::new(self)
+    }
+
     /// Get a [`BeatmapsetExtended`](crate::model::beatmap::BeatmapsetExtended) from a map ID.
     ///
     /// Filled options will be: `artist_unicode`, `converts`, `description`,
@@ -198,6 +327,115 @@ impl Osu {
         GetBeatmapsetSearch::new(self)
     }
 
+    /// Mark the chat as read up to a message and keep the chat session alive.
+    ///
+    /// Returns silences that were issued since the given silence id.
+    ///
+    /// Note that the client has to be initialized with the `ChatRead` scope
+    /// through the OAuth process in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub const fn chat_ack(&self) -> ChatAcknowledge<'_> {
+        ChatAcknowledge::new(self)
+    }
+
+    /// Get a [`ChatChannelDetails`](crate::model::chat::ChatChannelDetails)
+    /// containing a chat channel and its members.
+    ///
+    /// Note that the client has to be initialized with the `ChatRead` scope
+    /// through the OAuth process in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub const fn chat_channel(&self, channel_id: u64) -> GetChatChannel<'_> {
+        GetChatChannel::new(self, channel_id)
+    }
+
+    /// Get a vec of joinable public
+    /// [`ChatChannel`](crate::model::chat::ChatChannel)s.
+    ///
+    /// Note that the client has to be initialized with the `ChatRead` scope
+    /// through the OAuth process in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub const fn chat_channels(&self) -> GetChatChannels<'_> {
+        GetChatChannels::new(self)
+    }
+
+    /// Get a vec of the most recent
+    /// [`ChatMessage`](crate::model::chat::ChatMessage)s of a chat channel.
+    ///
+    /// Note that the client has to be initialized with the `ChatRead` scope
+    /// through the OAuth process in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub const fn chat_messages(&self, channel_id: u64) -> GetChatMessages<'_> {
+        GetChatMessages::new(self, channel_id)
+    }
+
+    /// Add a user to a chat channel, returning the joined
+    /// [`ChatChannel`](crate::model::chat::ChatChannel).
+    ///
+    /// The user id must be the id of the authorized user.
+    ///
+    /// Note that the client has to be initialized with the `ChatWriteManage`
+    /// scope through the OAuth process in order for this endpoint to not
+    /// return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub const fn join_chat_channel(&self, channel_id: u64, user_id: u32) -> JoinChatChannel<'_> {
+        JoinChatChannel::new(self, channel_id, user_id)
+    }
+
+    /// Remove a user from a chat channel.
+    ///
+    /// The user id must be the id of the authorized user.
+    ///
+    /// Note that the client has to be initialized with the `ChatWriteManage`
+    /// scope through the OAuth process in order for this endpoint to not
+    /// return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub const fn leave_chat_channel(&self, channel_id: u64, user_id: u32) -> LeaveChatChannel<'_> {
+        LeaveChatChannel::new(self, channel_id, user_id)
+    }
+
+    /// Send a message to a chat channel.
+    ///
+    /// Note that the client has to be initialized with the `ChatWrite` scope
+    /// through the OAuth process in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn send_chat_message(
+        &self,
+        channel_id: u64,
+        message: impl Into<String>,
+    ) -> SendChatMessage<'_> {
+        SendChatMessage::new(self, channel_id, message.into())
+    }
+
+    /// Send a private message to a user, creating a new chat channel if
+    /// necessary.
+    ///
+    /// Note that the client has to be initialized with the `ChatWrite` scope
+    /// through the OAuth process in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn send_private_message(
+        &self,
+        user_id: u32,
+        message: impl Into<String>,
+    ) -> SendPrivateMessage<'_> {
+        SendPrivateMessage::new(self, user_id, message.into())
+    }
+
     /// Get a list of comments and their replies up to two levels deep
     /// in form of a [`CommentBundle`](crate::model::comments::CommentBundle) .
     #[inline]
@@ -235,12 +473,87 @@ impl Osu {
         GetEvents::new(self)
     }
 
+    /// Get an [`EventWatcher`], a [`Stream`](futures::Stream) that polls the
+    /// global activity feed and yields new
+    /// [`Event`](crate::model::event::Event)s in chronological order.
+    #[inline]
+    pub const fn event_watcher(&self) -> EventWatcher<'_> {
+        EventWatcher::new(self)
+    }
+
+    /// Create a [`ForumTopic`](crate::model::forum::ForumTopic) in a forum,
+    /// optionally with a poll through [`CreateForumTopic::poll`].
+    ///
+    /// Note that the client has to be initialized with the `ForumWrite` scope
+    /// through the OAuth process in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn create_forum_topic(
+        &self,
+        forum_id: u32,
+        title: impl Into<String>,
+        body: impl Into<String>,
+    ) -> CreateForumTopic<'_> {
+        CreateForumTopic::new(self, forum_id, title.into(), body.into())
+    }
+
+    /// Edit the content of a [`ForumPost`](crate::model::forum::ForumPost).
+    ///
+    /// Note that the client has to be initialized with the `ForumWrite` scope
+    /// through the OAuth process in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn edit_forum_post(&self, post_id: u64, body: impl Into<String>) -> EditForumPost<'_> {
+        EditForumPost::new(self, post_id, body.into())
+    }
+
+    /// Reply to a [`ForumTopic`](crate::model::forum::ForumTopic) by creating
+    /// a [`ForumPost`](crate::model::forum::ForumPost).
+    ///
+    /// Note that the client has to be initialized with the `ForumWrite` scope
+    /// through the OAuth process in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn reply_forum_topic(&self, topic_id: u64, body: impl Into<String>) -> ReplyForumTopic<'_> {
+        ReplyForumTopic::new(self, topic_id, body.into())
+    }
+
+    /// Get [`ForumDetails`](crate::model::forum::ForumDetails) containing a
+    /// forum and its topics.
+    #[inline]
+    pub const fn forum(&self, forum_id: u32) -> GetForum<'_> {
+        GetForum::new(self, forum_id)
+    }
+
     /// Get a [`ForumPosts`](crate::model::forum::ForumPosts) struct for a forum topic
     #[inline]
     pub const fn forum_posts(&self, topic_id: u64) -> GetForumPosts<'_> {
         GetForumPosts::new(self, topic_id)
     }
 
+    /// Get a [`ForumTopic`](crate::model::forum::ForumTopic).
+    #[inline]
+    pub const fn forum_topic(&self, topic_id: u64) -> GetForumTopic<'_> {
+        GetForumTopic::new(self, topic_id)
+    }
+
+    /// Get [`ForumTopics`](crate::model::forum::ForumTopics), most recently
+    /// active first by default.
+    #[inline]
+    pub const fn forum_topics(&self) -> GetForumTopics<'_> {
+        GetForumTopics::new(self)
+    }
+
+    /// Get a vec of all [`Forum`](crate::model::forum::Forum)s including their
+    /// subforums.
+    #[inline]
+    pub const fn forums(&self) -> GetForums<'_> {
+        GetForums::new(self)
+    }
+
     /// Get all friends of the authenticated user as a vec of [`User`](crate::model::user::User).
     ///
     /// Note that the client has to be initialized with the `FriendsRead` scope
@@ -271,6 +584,18 @@ impl Osu {
         GetMatch::new(self, match_id)
     }
 
+    /// Get an [`OsuMatch`](crate::model::matches::OsuMatch) containing all
+    /// of its events.
+    ///
+    /// The osu!api sends at most 100 events per request so this method keeps
+    /// requesting earlier events until the match's first event and later
+    /// events until its latest event. Events are de-duplicated and the users
+    /// of all requests are merged.
+    #[inline]
+    pub const fn osu_match_full(&self, match_id: u32) -> GetMatchFull<'_> {
+        GetMatchFull::new(self, match_id)
+    }
+
     /// Get a [`MatchList`](crate::model::matches::MatchList) containing all
     /// currently open multiplayer lobbies.
     #[inline]
@@ -289,6 +614,17 @@ impl Osu {
         GetOwnData::new(self)
     }
 
+    /// Get the [`PlaylistScores`](crate::model::room::PlaylistScores) of a
+    /// multiplayer room's playlist item.
+    #[inline]
+    pub const fn playlist_scores(
+        &self,
+        room_id: u64,
+        playlist_item_id: u64,
+    ) -> GetPlaylistScores<'_> {
+        GetPlaylistScores::new(self, room_id, playlist_item_id)
+    }
+
     /// Get a [`Rankings`](crate::model::ranking::Rankings) struct whose
     /// [`User`](crate::model::user::User)s are sorted
     /// by their pp, i.e. the current pp leaderboard.
@@ -318,6 +654,26 @@ impl Osu {
         GetReplayRaw::new(self, score_id)
     }
 
+    /// Get a multiplayer [`Room`](crate::model::room::Room) including its
+    /// playlist.
+    #[inline]
+    pub const fn room(&self, room_id: u64) -> GetRoom<'_> {
+        GetRoom::new(self, room_id)
+    }
+
+    /// Get the [`RoomLeaderboard`](crate::model::room::RoomLeaderboard) of a
+    /// multiplayer room, e.g. of a daily challenge.
+    #[inline]
+    pub const fn room_leaderboard(&self, room_id: u64) -> GetRoomLeaderboard<'_> {
+        GetRoomLeaderboard::new(self, room_id)
+    }
+
+    /// Get a vec of multiplayer [`Room`](crate::model::room::Room)s.
+    #[inline]
+    pub const fn rooms(&self) -> GetRooms<'_> {
+        GetRooms::new(self)
+    }
+
     /// Get a [`Score`](crate::model::score::Score) struct.
     ///
     /// The contained score will have the following options filled:
@@ -337,6 +693,13 @@ impl Osu {
         GetScores::new(self)
     }
 
+    /// Get a [`ScoreFeed`], a [`Stream`](futures::Stream) that continuously
+    /// polls recently processed scores and yields each score exactly once.
+    #[inline]
+    pub const fn score_feed(&self) -> ScoreFeed<'_> {
+        ScoreFeed::new(self)
+    }
+
     /// Get a [`Rankings`](crate::model::ranking::Rankings) struct whose
     /// [`User`](crate::model::user::User)s are sorted
     /// by their ranked score, i.e. the current ranked score leaderboard.
@@ -436,7 +799,10 @@ impl Osu {
         GetUserScores::new(self, user_id.into())
     }
 
-    /// Get a vec of [`User`](crate::model::user::User).
+    /// Get a vec of at most 50 [`User`](crate::model::user::User)s.
+    ///
+    /// All ids after the 50th are ignored; use [`users_bulk`](Osu::users_bulk)
+    /// for more ids.
     #[inline]
     pub fn users<I>(&self, user_ids: I) -> GetUsers<'_>
     where
@@ -445,6 +811,24 @@ impl Osu {
         GetUsers::new(self, user_ids)
     }
 
+    /// Get a [`BulkLookup`](crate::model::BulkLookup) of
+    /// [`User`](crate::model::user::User)s for any amount of ids.
+    ///
+    /// The ids are requested concurrently in batches of 50. The found users
+    /// are in the order of the given ids and ids for which no user was found
+    /// are listed separately. Duplicate ids are only requested and listed
+    /// once.
+    ///
+    /// If any batch fails, the whole lookup fails with that error and the
+    /// results of the other batches are discarded.
+    #[inline]
+    pub fn users_bulk<I>(&self, user_ids: I) -> GetUsersBulk<'_>
+    where
+        I: IntoIterator<Item = u32>,
+    {
+        GetUsersBulk::new(self, user_ids)
+    }
+
     /// Get a [`WikiPage`](crate::model::wiki::WikiPage) or image data.
     ///
     /// `locale` adjusts the language, e.g. `en` for english, `de` for german, ...
@@ -465,13 +849,21 @@ impl Drop for Osu {
 pub(crate) struct OsuInner {
     pub(crate) client_id: u64,
     pub(crate) client_secret: Box<str>,
-    pub(crate) http: HyperClient<HttpsConnector<HttpConnector>, Full<Bytes>>,
+    pub(crate) api_url: Box<str>,
+    pub(crate) oauth_url: Box<str>,
+    pub(crate) http: Box<dyn Transport>,
     pub(crate) timeout: Duration,
     pub(crate) ratelimiter: Arc<RateLimiter>,
+    pub(crate) adaptive_ratelimit: AdaptiveRatelimit,
+    pub(crate) priority_gate: PriorityGate,
     pub(crate) token: CurrentToken,
-    pub(crate) retries: u8,
+    pub(crate) retry: RetryPolicy,
+    pub(crate) middlewares: Box<[Box<dyn Middleware>]>,
+    pub(crate) response_cache: Option<CachedResponses>,
+    #[cfg(feature = "cache")]
+    pub(crate) cache: Box<dyn crate::cache::UserIdCache>,
     #[cfg(feature = "cache")]
-    pub(crate) cache: dashmap::DashMap<crate::prelude::Username, u32>,
+    pub(crate) reverify_cached_names: bool,
 }
 
 #[cfg(feature = "cache")]
@@ -481,4 +873,18 @@ impl OsuInner {
         name.make_ascii_lowercase();
         self.cache.insert(name, user_id);
     }
+
+    pub(crate) fn evict_from_cache(&self, username: &crate::prelude::Username) {
+        let mut name = username.to_owned();
+        name.make_ascii_lowercase();
+        self.cache.remove(&name);
+    }
+
+    /// Evict a previous name of the given user unless it belongs to someone
+    /// else by now.
+    pub(crate) fn evict_stale_name(&self, user_id: u32, username: &crate::prelude::Username) {
+        let mut name = username.to_owned();
+        name.make_ascii_lowercase();
+        self.cache.remove_if(&name, user_id);
+    }
 }
@@ -0,0 +1,327 @@
+use std::{
+    cmp::Reverse,
+    collections::BTreeMap,
+    sync::{Mutex, MutexGuard, PoisonError},
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+use hyper::{HeaderMap, StatusCode};
+
+use super::retry::parse_retry_after;
+
+const X_RATELIMIT_LIMIT: &str = "x-ratelimit-limit";
+const X_RATELIMIT_REMAINING: &str = "x-ratelimit-remaining";
+
+/// How long to pause all requests after a 429 response without `Retry-After`.
+const DEFAULT_PAUSE: Duration = Duration::from_secs(5);
+
+/// Length of the osu!api's ratelimit window.
+const WINDOW: Duration = Duration::from_mins(1);
+
+/// The client's request budget as last reported by the osu!api through the
+/// `X-RateLimit-Limit` and `X-RateLimit-Remaining` headers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RatelimitBudget {
+    /// Amount of requests allowed per minute
+    pub limit: u32,
+    /// Estimated amount of requests remaining in the current minute
+    pub remaining: u32,
+    /// If the osu!api responded with a 429 recently, all requests are paused
+    /// for this long
+    pub paused_for: Option<Duration>,
+}
+
+/// Adjusts the pacing of requests based on the osu!api's ratelimit headers.
+///
+/// While the budget is plentiful, requests are only paced by the static
+/// ratelimiter configured through [`OsuBuilder::ratelimit`]. Once the
+/// remaining budget drops below a tenth of the limit, requests are spread
+/// out so that the server-side budget can replenish. After a 429 response,
+/// all requests are paused.
+///
+/// [`OsuBuilder::ratelimit`]: crate::OsuBuilder::ratelimit
+pub(crate) struct AdaptiveRatelimit {
+    state: Mutex<AdaptiveState>,
+}
+
+#[derive(Default)]
+struct AdaptiveState {
+    limit: Option<u32>,
+    remaining: u32,
+    paused_until: Option<Instant>,
+    /// While throttling, the earliest time the next request may be sent
+    next_send: Option<Instant>,
+}
+
+impl AdaptiveRatelimit {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: Mutex::new(AdaptiveState::default()),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, AdaptiveState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Learn from the given response.
+    pub(crate) fn update(&self, status: StatusCode, headers: &HeaderMap) {
+        fn parse_header(headers: &HeaderMap, name: &str) -> Option<u32> {
+            headers.get(name)?.to_str().ok()?.trim().parse().ok()
+        }
+
+        let mut state = self.state();
+
+        if let Some(limit) = parse_header(headers, X_RATELIMIT_LIMIT) {
+            state.limit = Some(limit.max(1));
+        }
+
+        if let Some(remaining) = parse_header(headers, X_RATELIMIT_REMAINING) {
+            state.remaining = remaining;
+        }
+
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            let pause = parse_retry_after(headers).unwrap_or(DEFAULT_PAUSE);
+            let until = Instant::now() + pause;
+            state.remaining = 0;
+
+            if state.paused_until.is_none_or(|prev| prev < until) {
+                warn!("Received 429, pausing requests for {pause:?}");
+                state.paused_until = Some(until);
+            }
+        }
+    }
+
+    /// Returns how long to wait before sending the next request and accounts
+    /// for the request being sent.
+    pub(crate) fn delay(&self) -> Option<Duration> {
+        let mut state = self.state();
+        let now = Instant::now();
+
+        if let Some(until) = state.paused_until {
+            if until > now {
+                return Some(until - now);
+            }
+
+            state.paused_until = None;
+        }
+
+        let limit = state.limit?;
+
+        // Still plenty of budget
+        if state.remaining > limit / 10 {
+            state.remaining -= 1;
+
+            return None;
+        }
+
+        state.remaining = state.remaining.saturating_sub(1);
+
+        // Each request waits as long as it takes the server to replenish one
+        // request after the previously throttled request so that concurrent
+        // requests are spread out instead of being sent all at once.
+        let send_at = state.next_send.map_or(now, |next| next.max(now)) + WINDOW / limit;
+        state.next_send = Some(send_at);
+
+        Some(send_at - now)
+    }
+
+    pub(crate) fn budget(&self) -> Option<RatelimitBudget> {
+        let state = self.state();
+        let now = Instant::now();
+
+        Some(RatelimitBudget {
+            limit: state.limit?,
+            remaining: state.remaining,
+            paused_for: state
+                .paused_until
+                .filter(|until| *until > now)
+                .map(|until| until - now),
+        })
+    }
+}
+
+/// Priority of requests when waiting for the ratelimiter.
+///
+/// Requests with higher priority are sent before requests with lower
+/// priority. To ensure that low-priority requests still make progress, every
+/// fourth request that passes the ratelimiter is the one that has been
+/// waiting the longest, regardless of its priority.
+///
+/// See [`Osu::with_priority`](crate::Osu::with_priority).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// E.g. background jobs
+    Low,
+    /// The default
+    #[default]
+    Normal,
+    /// E.g. interactive commands
+    High,
+}
+
+/// Every n-th permit is granted to the oldest waiter.
+const FAIRNESS_INTERVAL: u64 = 4;
+
+/// Determines which of the waiting requests may acquire from the ratelimiter
+/// next.
+///
+/// Only a single request, the "holder", may acquire at a time. Once it's done,
+/// it releases the gate and the next holder is selected.
+pub(crate) struct PriorityGate {
+    state: Mutex<GateState>,
+}
+
+#[derive(Default)]
+struct GateState {
+    holder: Option<u64>,
+    /// Waiting requests by their id. Ids are handed out incrementally so the
+    /// first entry is always the oldest.
+    waiting: BTreeMap<u64, Waiter>,
+    next_id: u64,
+    grants: u64,
+}
+
+struct Waiter {
+    priority: Priority,
+    waker: Option<Waker>,
+}
+
+impl GateState {
+    /// Picks the next holder and wakes it.
+    fn select_next(&mut self) {
+        let next = if self.grants % FAIRNESS_INTERVAL == FAIRNESS_INTERVAL - 1 {
+            self.waiting.keys().next().copied()
+        } else {
+            // Highest priority first; among equal priorities the oldest
+            self.waiting
+                .iter()
+                .min_by_key(|(id, waiter)| (Reverse(waiter.priority), **id))
+                .map(|(id, _)| *id)
+        };
+
+        let Some(id) = next else { return };
+        self.holder = Some(id);
+
+        if let Some(waker) = self.waiting.remove(&id).and_then(|waiter| waiter.waker) {
+            waker.wake();
+        }
+    }
+}
+
+impl PriorityGate {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: Mutex::new(GateState::default()),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, GateState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Enqueue a new request and return its id.
+    pub(crate) fn enter(&self, priority: Priority) -> u64 {
+        let mut state = self.state();
+        let id = state.next_id;
+        state.next_id += 1;
+
+        let waiter = Waiter {
+            priority,
+            waker: None,
+        };
+
+        state.waiting.insert(id, waiter);
+
+        id
+    }
+
+    /// Ready once the request with the given id may acquire.
+    pub(crate) fn poll_turn(&self, id: u64, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state();
+
+        if state.holder.is_none() {
+            state.select_next();
+        }
+
+        if state.holder == Some(id) {
+            return Poll::Ready(());
+        }
+
+        if let Some(waiter) = state.waiting.get_mut(&id) {
+            waiter.waker = Some(cx.waker().clone());
+        }
+
+        Poll::Pending
+    }
+
+    /// Remove the request with the given id, either because it acquired from
+    /// the ratelimiter or because it was dropped.
+    pub(crate) fn leave(&self, id: u64) {
+        let mut state = self.state();
+
+        if state.holder == Some(id) {
+            state.holder = None;
+            state.grants += 1;
+            state.select_next();
+        } else {
+            state.waiting.remove(&id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::task::{Context, Waker};
+
+    use super::*;
+
+    #[test]
+    fn adaptive_delay_spreads_out() {
+        let ratelimit = AdaptiveRatelimit::new();
+
+        {
+            let mut state = ratelimit.state();
+            state.limit = Some(60);
+            state.remaining = 0;
+        }
+
+        let delays: Vec<_> = (0..3).map(|_| ratelimit.delay().unwrap()).collect();
+
+        // Concurrent requests are queued one second apart
+        for (i, delay) in delays.into_iter().enumerate() {
+            let expected = Duration::from_secs(i as u64 + 1);
+            assert!(expected.checked_sub(delay).unwrap() < Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn priority_gate_fairness() {
+        let gate = PriorityGate::new();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let low = gate.enter(Priority::Low);
+        let normal = gate.enter(Priority::Normal);
+        let high: Vec<_> = (0..6).map(|_| gate.enter(Priority::High)).collect();
+
+        let mut order = Vec::new();
+
+        while order.len() < 8 {
+            let ready = [low, normal]
+                .into_iter()
+                .chain(high.iter().copied())
+                .find(|id| !order.contains(id) && gate.poll_turn(*id, &mut cx).is_ready())
+                .unwrap();
+
+            gate.leave(ready);
+            order.push(ready);
+        }
+
+        // Three by priority, then the oldest, then three by priority again, ...
+        let expected = [
+            high[0], high[1], high[2], low, high[3], high[4], high[5], normal,
+        ];
+        assert_eq!(order, expected);
+    }
+}
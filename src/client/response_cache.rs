@@ -0,0 +1,332 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use bytes::Bytes;
+use hyper::{
+    header::{HeaderValue, ETAG, LAST_MODIFIED},
+    HeaderMap,
+};
+
+/// Configuration of an in-memory cache for API responses.
+///
+/// Only successful `GET` responses of routes with a TTL are cached. Within
+/// their TTL, cached responses are returned right away without waiting for
+/// the ratelimiter or passing through middlewares. Once the TTL expired,
+/// responses that came with an `ETag` or `Last-Modified` header are
+/// revalidated through a conditional request so that the body does not need
+/// to be transferred again if it did not change.
+///
+/// Routes are identified by their name, e.g. `"GetBeatmap"`,
+/// `"GetBeatmapset"`, `"GetWikiPage"`, or `"GetUserScores/Best"`. These are
+/// the same names that are used as `route` label by the `metrics` feature.
+///
+/// If the cache is full, the least recently used responses are evicted.
+///
+/// ## Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use rosu_v2::{Osu, ResponseCache};
+///
+/// let cache = ResponseCache::new()
+///     .max_entries(10_000)
+///     .ttl("GetBeatmap", Duration::from_mins(10))
+///     .ttl("GetBeatmapset", Duration::from_mins(10))
+///     .ttl("GetWikiPage", Duration::from_hours(1));
+///
+/// let builder = Osu::builder().response_cache(cache);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ResponseCache {
+    max_entries: usize,
+    max_bytes: Option<usize>,
+    default_ttl: Option<Duration>,
+    ttls: HashMap<Box<str>, Duration>,
+}
+
+impl Default for ResponseCache {
+    fn default() -> Self {
+        Self {
+            max_entries: 1000,
+            max_bytes: None,
+            default_ttl: None,
+            ttls: HashMap::new(),
+        }
+    }
+}
+
+impl ResponseCache {
+    /// Create a new [`ResponseCache`] that does not cache any routes yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep at most this many responses, defaults to 1000.
+    #[must_use]
+    pub const fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+
+        self
+    }
+
+    /// Keep at most this many bytes of response bodies. Unlimited by default.
+    #[must_use]
+    pub const fn max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+
+        self
+    }
+
+    /// Cache responses of the given route for the given duration.
+    #[must_use]
+    pub fn ttl(mut self, route: &str, ttl: Duration) -> Self {
+        self.ttls.insert(Box::from(route), ttl);
+
+        self
+    }
+
+    /// Cache responses of routes without a specified TTL for the given
+    /// duration. By default, such routes are not cached.
+    #[must_use]
+    pub const fn default_ttl(mut self, ttl: Duration) -> Self {
+        self.default_ttl = Some(ttl);
+
+        self
+    }
+}
+
+/// Where to store the response of a request.
+pub(crate) struct CacheSlot {
+    pub(crate) key: Arc<str>,
+    pub(crate) ttl: Duration,
+}
+
+/// Validators of a response to revalidate it once it's stale.
+#[derive(Clone, Default)]
+pub(crate) struct Validators {
+    pub(crate) etag: Option<HeaderValue>,
+    pub(crate) last_modified: Option<HeaderValue>,
+}
+
+impl Validators {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Self {
+        Self {
+            etag: headers.get(ETAG).cloned(),
+            last_modified: headers.get(LAST_MODIFIED).cloned(),
+        }
+    }
+}
+
+pub(crate) enum Lookup {
+    Fresh(Bytes),
+    Stale(Validators),
+    Miss,
+}
+
+/// The cached responses of a client.
+pub(crate) struct CachedResponses {
+    config: ResponseCache,
+    state: Mutex<CacheState>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<Arc<str>, CacheEntry>,
+    /// Keys by the time they were last used; the first is the least recently
+    /// used.
+    lru: BTreeMap<u64, Arc<str>>,
+    tick: u64,
+    bytes: usize,
+}
+
+struct CacheEntry {
+    bytes: Bytes,
+    expires_at: Instant,
+    validators: Validators,
+    last_used: u64,
+}
+
+impl CacheState {
+    fn touch(&mut self, key: &Arc<str>) {
+        let tick = self.tick;
+        self.tick += 1;
+
+        if let Some(entry) = self.entries.get_mut(key) {
+            self.lru.remove(&entry.last_used);
+            entry.last_used = tick;
+            self.lru.insert(tick, Arc::clone(key));
+        }
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.lru.remove(&entry.last_used);
+            self.bytes -= entry.bytes.len();
+        }
+    }
+
+    fn evict_lru(&mut self) {
+        if let Some((_, key)) = self.lru.pop_first() {
+            if let Some(entry) = self.entries.remove(&key) {
+                self.bytes -= entry.bytes.len();
+            }
+        }
+    }
+}
+
+impl CachedResponses {
+    pub(crate) fn new(config: ResponseCache) -> Self {
+        Self {
+            config,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, CacheState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// The TTL for responses of the given route, if they should be cached.
+    pub(crate) fn ttl(&self, route: &str) -> Option<Duration> {
+        self.config
+            .ttls
+            .get(route)
+            .copied()
+            .or(self.config.default_ttl)
+    }
+
+    pub(crate) fn lookup(&self, key: &Arc<str>) -> Lookup {
+        let mut state = self.state();
+
+        let Some(entry) = state.entries.get(key) else {
+            return Lookup::Miss;
+        };
+
+        if entry.expires_at > Instant::now() {
+            let bytes = entry.bytes.clone();
+            state.touch(key);
+
+            return Lookup::Fresh(bytes);
+        }
+
+        if entry.validators.etag.is_none() && entry.validators.last_modified.is_none() {
+            state.remove(key);
+
+            return Lookup::Miss;
+        }
+
+        Lookup::Stale(entry.validators.clone())
+    }
+
+    /// Store a successful response.
+    pub(crate) fn insert(&self, slot: &CacheSlot, bytes: Bytes, validators: Validators) {
+        if self.config.max_entries == 0
+            || self.config.max_bytes.is_some_and(|max| bytes.len() > max)
+        {
+            return;
+        }
+
+        let mut state = self.state();
+        state.remove(&slot.key);
+
+        let len = bytes.len();
+
+        let entry = CacheEntry {
+            bytes,
+            expires_at: Instant::now() + slot.ttl,
+            validators,
+            last_used: 0,
+        };
+
+        while state.entries.len() >= self.config.max_entries
+            || self
+                .config
+                .max_bytes
+                .is_some_and(|max| state.bytes + len > max)
+        {
+            state.evict_lru();
+        }
+
+        state.bytes += len;
+        state.entries.insert(Arc::clone(&slot.key), entry);
+        state.touch(&slot.key);
+    }
+
+    /// Refresh a stale response after the osu!api responded with a 304.
+    pub(crate) fn revalidate(&self, slot: &CacheSlot) -> Option<Bytes> {
+        let mut state = self.state();
+        let entry = state.entries.get_mut(&slot.key)?;
+        entry.expires_at = Instant::now() + slot.ttl;
+        let bytes = entry.bytes.clone();
+        state.touch(&slot.key);
+
+        Some(bytes)
+    }
+
+    pub(crate) fn clear(&self) {
+        *self.state() = CacheState::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot(key: &str) -> CacheSlot {
+        CacheSlot {
+            key: Arc::from(key),
+            ttl: Duration::from_mins(1),
+        }
+    }
+
+    #[test]
+    fn lru_eviction() {
+        let cache = CachedResponses::new(ResponseCache::new().max_entries(2).max_bytes(10));
+
+        cache.insert(
+            &slot("a"),
+            Bytes::from_static(b"aaa"),
+            Validators::default(),
+        );
+        cache.insert(
+            &slot("b"),
+            Bytes::from_static(b"bbb"),
+            Validators::default(),
+        );
+
+        // Use "a" so that "b" becomes the least recently used
+        assert!(matches!(cache.lookup(&slot("a").key), Lookup::Fresh(_)));
+        cache.insert(
+            &slot("c"),
+            Bytes::from_static(b"ccc"),
+            Validators::default(),
+        );
+
+        assert!(matches!(cache.lookup(&slot("a").key), Lookup::Fresh(_)));
+        assert!(matches!(cache.lookup(&slot("b").key), Lookup::Miss));
+        assert!(matches!(cache.lookup(&slot("c").key), Lookup::Fresh(_)));
+
+        // Exceeds the byte limit so both "a" and "c" are evicted
+        cache.insert(
+            &slot("d"),
+            Bytes::from_static(b"dddddddd"),
+            Validators::default(),
+        );
+
+        assert!(matches!(cache.lookup(&slot("a").key), Lookup::Miss));
+        assert!(matches!(cache.lookup(&slot("c").key), Lookup::Miss));
+        assert!(matches!(cache.lookup(&slot("d").key), Lookup::Fresh(_)));
+
+        // Too large to be cached at all
+        cache.insert(
+            &slot("e"),
+            Bytes::from_static(b"eeeeeeeeeee"),
+            Validators::default(),
+        );
+        assert!(matches!(cache.lookup(&slot("e").key), Lookup::Miss));
+    }
+}
@@ -0,0 +1,253 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use hyper::{header::RETRY_AFTER, HeaderMap, StatusCode};
+use time::{format_description::well_known::Rfc2822, OffsetDateTime};
+
+/// Determines whether and how failed requests are retried.
+///
+/// Requests are retried if they time out or if the response status is one of
+/// the specified statuses. Between attempts, the client waits through
+/// exponential backoff starting at `initial_backoff` and doubling on each
+/// attempt up to `max_backoff`. If the response contains a `Retry-After`
+/// header, its value takes precedence.
+///
+/// Requests other than GET requests are only retried on 429 responses since
+/// the osu!api might have processed them already otherwise, e.g. sending a
+/// chat message twice.
+///
+/// The default policy retries up to 2 times on timeouts and on the statuses
+/// 429, 502, 503, and 504 with an initial backoff of 500ms, a max backoff of
+/// 30 seconds, jitter, and respecting `Retry-After`.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    pub(crate) max_retries: u8,
+    statuses: Vec<StatusCode>,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    max_elapsed: Option<Duration>,
+    jitter: bool,
+    retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            max_elapsed: None,
+            jitter: true,
+            retry_after: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a new default [`RetryPolicy`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A [`RetryPolicy`] that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Retry up to this many times, defaults to 2.
+    #[must_use]
+    pub const fn max_retries(mut self, max_retries: u8) -> Self {
+        self.max_retries = max_retries;
+
+        self
+    }
+
+    /// Retry if the response has one of these statuses, defaults to 429,
+    /// 502, 503, and 504.
+    ///
+    /// Timeouts of GET requests are always retried.
+    #[must_use]
+    pub fn statuses(mut self, statuses: impl IntoIterator<Item = StatusCode>) -> Self {
+        self.statuses = statuses.into_iter().collect();
+
+        self
+    }
+
+    /// Wait this long before the first retry, defaults to 500ms.
+    ///
+    /// The backoff doubles on each further attempt.
+    #[must_use]
+    pub const fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+
+        self
+    }
+
+    /// Never wait longer than this between attempts, defaults to 30 seconds.
+    ///
+    /// This does not apply to durations specified by `Retry-After` headers.
+    #[must_use]
+    pub const fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+
+        self
+    }
+
+    /// Don't retry if the next attempt would start later than this duration
+    /// after the request was first issued. Unlimited by default.
+    #[must_use]
+    pub const fn max_elapsed(mut self, max_elapsed: Duration) -> Self {
+        self.max_elapsed = Some(max_elapsed);
+
+        self
+    }
+
+    /// Whether backoff durations should be randomized, defaults to `true`.
+    ///
+    /// With jitter, the actual backoff lies between half and the full
+    /// computed backoff so that concurrent requests don't retry all at once.
+    #[must_use]
+    pub const fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+
+        self
+    }
+
+    /// Whether `Retry-After` headers of responses should determine the
+    /// backoff, defaults to `true`.
+    #[must_use]
+    pub const fn retry_after(mut self, retry_after: bool) -> Self {
+        self.retry_after = retry_after;
+
+        self
+    }
+
+    /// Whether responses with the given status should be retried.
+    pub(crate) fn retries_status(&self, status: StatusCode) -> bool {
+        self.statuses.contains(&status)
+    }
+
+    /// Returns the duration to wait before the given attempt or `None` if
+    /// the request should not be retried anymore.
+    ///
+    /// `attempt` is the 1-based number of the upcoming retry and `elapsed`
+    /// the duration since the request was first issued.
+    pub(crate) fn backoff(
+        &self,
+        attempt: u8,
+        elapsed: Duration,
+        headers: Option<&HeaderMap>,
+    ) -> Option<Duration> {
+        if attempt > self.max_retries {
+            return None;
+        }
+
+        let retry_after = headers
+            .filter(|_| self.retry_after)
+            .and_then(parse_retry_after);
+
+        let backoff = retry_after.unwrap_or_else(|| {
+            let exp = u32::from(attempt.saturating_sub(1)).min(16);
+            let backoff = self
+                .initial_backoff
+                .saturating_mul(1 << exp)
+                .min(self.max_backoff);
+
+            if self.jitter {
+                let half = backoff / 2;
+
+                half + half.mul_f64(random_fraction())
+            } else {
+                backoff
+            }
+        });
+
+        match self.max_elapsed {
+            Some(max_elapsed) if elapsed + backoff > max_elapsed => None,
+            _ => Some(backoff),
+        }
+    }
+}
+
+/// Parses a `Retry-After` header as either delay-seconds or HTTP-date.
+pub(crate) fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(secs) = value.parse() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let date = OffsetDateTime::parse(value, &Rfc2822).ok()?;
+    let secs = (date - OffsetDateTime::now_utc())
+        .whole_milliseconds()
+        .max(0);
+
+    Some(Duration::from_millis(secs as u64))
+}
+
+/// A random value in `[0, 1)`.
+fn random_fraction() -> f64 {
+    // `RandomState` is seeded randomly on each construction
+    let hasher = RandomState::new().build_hasher();
+
+    (hasher.finish() >> 11) as f64 / (1_u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper::header::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn exponential_backoff() {
+        let policy = RetryPolicy::new()
+            .max_retries(5)
+            .jitter(false)
+            .max_backoff(Duration::from_secs(3));
+
+        let backoffs: Vec<_> = (1..=6)
+            .map(|attempt| policy.backoff(attempt, Duration::ZERO, None))
+            .collect();
+
+        let expected = [500, 1000, 2000, 3000, 3000]
+            .map(|ms| Some(Duration::from_millis(ms)))
+            .into_iter()
+            .chain([None])
+            .collect::<Vec<_>>();
+
+        assert_eq!(backoffs, expected);
+
+        let policy = policy.max_elapsed(Duration::from_secs(2));
+        assert_eq!(policy.backoff(2, Duration::from_millis(1500), None), None);
+    }
+
+    #[test]
+    fn retry_after() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        assert_eq!(parse_retry_after(&headers), Some(Duration::from_secs(7)));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(parse_retry_after(&headers), Some(Duration::ZERO));
+
+        let policy = RetryPolicy::new().retry_after(false).jitter(false);
+        let backoff = policy.backoff(1, Duration::ZERO, Some(&headers));
+        assert_eq!(backoff, Some(Duration::from_millis(500)));
+    }
+}
@@ -244,6 +244,7 @@ pub(super) enum AuthorizationBuilder {
 impl AuthorizationBuilder {
     #[cfg(feature = "local_oauth")]
     pub(super) async fn perform_local_oauth(
+        oauth_url: &str,
         redirect_uri: String,
         client_id: u64,
         scopes: Scopes,
@@ -276,7 +277,7 @@ impl AuthorizationBuilder {
             .map_err(OAuthError::Listener)?;
 
         let mut url = format!(
-            "https://osu.ppy.sh/oauth/authorize?\
+            "{oauth_url}authorize?\
                 client_id={client_id}\
                 &redirect_uri={redirect_uri}\
                 &response_type=code",
@@ -352,8 +353,10 @@ You may close this tab
     }
 }
 
+#[derive(Default)]
 pub(super) enum AuthorizationKind {
     User(Authorization),
+    #[default]
     Client,
     BareToken,
 }
@@ -380,12 +383,6 @@ impl AuthorizationKind {
     }
 }
 
-impl Default for AuthorizationKind {
-    fn default() -> Self {
-        Self::Client
-    }
-}
-
 pub(crate) struct Authorization {
     pub code: Box<str>,
     pub redirect_uri: Box<str>,
@@ -1,11 +1,10 @@
-use hyper::{
-    body::Bytes, header::InvalidHeaderValue, http::Error as HttpError, Error as HyperError,
-    StatusCode,
-};
+use hyper::{body::Bytes, header::InvalidHeaderValue, http::Error as HttpError, StatusCode};
 use serde::Deserialize;
 use serde_json::Error as SerdeError;
 use std::fmt;
 
+use crate::transport::{ResponseBody, TransportError};
+
 #[cfg(feature = "local_oauth")]
 #[cfg_attr(docsrs, doc(cfg(feature = "local_oauth")))]
 #[derive(Debug, thiserror::Error)]
@@ -60,7 +59,7 @@ pub enum OsuError {
     #[error("failed to chunk the response")]
     ChunkingResponse {
         #[source]
-        source: HyperError,
+        source: TransportError,
     },
     /// No usable cipher suites in crypto provider
     #[error("no usable cipher suites in crypto provider")]
@@ -118,7 +117,7 @@ pub enum OsuError {
     #[error("failed to send request")]
     Request {
         #[source]
-        source: hyper_util::client::legacy::Error,
+        source: TransportError,
     },
     /// Timeout while requesting from API
     #[error("osu!api did not respond in time")]
@@ -133,7 +132,7 @@ pub enum OsuError {
     },
     /// Temporal (?) downtime of the osu API
     #[error("osu!api may be temporarily unavailable (received 503)")]
-    ServiceUnavailable { body: hyper::body::Incoming },
+    ServiceUnavailable { body: ResponseBody },
     /// The client's authentication is not sufficient for the endpoint
     #[error("the endpoint is not available for the client's authorization level")]
     UnavailableEndpoint,
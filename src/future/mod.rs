@@ -9,6 +9,9 @@ use std::{
 use pin_project::pin_project;
 
 use crate::{
+    client::{OsuInner, Priority},
+    error::OsuError,
+    model::user::Username,
     request::{GetUser, Request, UserId},
     Osu, OsuResult,
 };
@@ -52,8 +55,14 @@ struct PostProcess<T: OsuFutureData> {
 pub struct OsuFuture<T: OsuFutureData> {
     #[pin]
     stage: OsuFutureStage,
+    priority: Priority,
     from_user: Option<FromUser<T>>,
     post_process: Option<PostProcess<T>>,
+    /// Cached name and id that should be fetched again if the request fails
+    /// with a 404.
+    reverify: Option<(Username, u32, Arc<OsuInner>)>,
+    /// Cached id of a name that is currently being fetched again.
+    reverified_id: Option<u32>,
 }
 
 impl<T: OsuFutureData> OsuFuture<T> {
@@ -64,16 +73,20 @@ impl<T: OsuFutureData> OsuFuture<T> {
         post_process_data: T::PostProcessData,
         post_process_fn: PostProcessFn<T>,
     ) -> Self {
+        let priority = osu.priority;
         let osu = Arc::clone(&osu.inner);
 
         Self {
-            stage: OsuRequestStageInner::new(osu, req)
+            stage: OsuRequestStageInner::new(osu, req, priority)
                 .map_or_else(OsuFutureStage::Failed, OsuFutureStage::Final),
+            priority,
             from_user: None,
             post_process: Some(PostProcess {
                 data: post_process_data,
                 f: post_process_fn,
             }),
+            reverify: None,
+            reverified_id: None,
         }
     }
 
@@ -88,19 +101,27 @@ impl<T: OsuFutureData> OsuFuture<T> {
         post_process_fn: PostProcessFn<T>,
     ) -> Self {
         #[cfg(not(feature = "cache"))]
-        let get_user_id: fn(UserId) -> UserId = std::convert::identity;
+        const fn get_user_id(user_id: UserId) -> (UserId, Option<Username>) {
+            (user_id, None)
+        }
 
+        /// Also returns the cached name if it should be re-verified.
         #[cfg(feature = "cache")]
-        fn get_user_id(mut user_id: UserId, osu: &Osu) -> UserId {
+        fn get_user_id(mut user_id: UserId, osu: &Osu) -> (UserId, Option<Username>) {
             if let UserId::Name(ref mut name) = user_id {
                 name.make_ascii_lowercase();
 
                 if let Some(id) = osu.inner.cache.get(name) {
-                    return UserId::Id(*id);
+                    let name = osu
+                        .inner
+                        .reverify_cached_names
+                        .then(|| std::mem::take(name));
+
+                    return (UserId::Id(id), name);
                 }
             }
 
-            user_id
+            (user_id, None)
         }
 
         match get_user_id(
@@ -108,12 +129,24 @@ impl<T: OsuFutureData> OsuFuture<T> {
             #[cfg(feature = "cache")]
             osu,
         ) {
-            UserId::Id(user_id) => {
+            (UserId::Id(user_id), Some(name)) => {
+                let req = from_user_fn(user_id, from_user_data.clone());
+                let mut fut = Self::new(osu, req, post_process_data, post_process_fn);
+
+                fut.from_user = Some(FromUser {
+                    data: from_user_data,
+                    f: from_user_fn,
+                });
+                fut.reverify = Some((name, user_id, Arc::clone(&osu.inner)));
+
+                fut
+            }
+            (UserId::Id(user_id), None) => {
                 let req = from_user_fn(user_id, from_user_data);
 
                 Self::new(osu, req, post_process_data, post_process_fn)
             }
-            user_id @ UserId::Name(_) => {
+            (user_id @ UserId::Name(_), _) => {
                 #[cfg(not(feature = "cache"))]
                 {
                     static NOTIF: std::sync::Once = std::sync::Once::new();
@@ -132,12 +165,14 @@ impl<T: OsuFutureData> OsuFuture<T> {
                     });
                 }
 
+                let priority = osu.priority;
                 let osu = Arc::clone(&osu.inner);
                 let req = GetUser::create_request(user_id, None);
 
                 Self {
-                    stage: OsuRequestStageInner::new(osu, req)
+                    stage: OsuRequestStageInner::new(osu, req, priority)
                         .map_or_else(OsuFutureStage::Failed, OsuFutureStage::User),
+                    priority,
                     from_user: Some(FromUser {
                         data: from_user_data,
                         f: from_user_fn,
@@ -146,6 +181,8 @@ impl<T: OsuFutureData> OsuFuture<T> {
                         data: post_process_data,
                         f: post_process_fn,
                     }),
+                    reverify: None,
+                    reverified_id: None,
                 }
             }
         }
@@ -167,9 +204,17 @@ impl<T: OsuFutureData> Future for OsuFuture<T> {
                 let value = f(res, data)?;
 
                 #[cfg(feature = "cache")]
-                crate::model::ContainedUsers::apply_to_users(&value, |id, name| {
-                    osu.update_cache(id, name);
-                });
+                {
+                    // Evict stale names first in case a user reclaimed a
+                    // previous name of theirs
+                    crate::model::ContainedUsers::apply_to_stale_names(&value, |id, name| {
+                        osu.evict_stale_name(id, name);
+                    });
+
+                    crate::model::ContainedUsers::apply_to_users(&value, |id, name| {
+                        osu.update_cache(id, name);
+                    });
+                }
 
                 // Preventing "unused variable" lint w/o `cache` feature
                 let _ = osu;
@@ -178,12 +223,24 @@ impl<T: OsuFutureData> Future for OsuFuture<T> {
             }
             Poll::Ready(ControlFlow::Continue((user, osu))) => {
                 #[cfg(feature = "cache")]
-                osu.update_cache(user.user_id, &user.username);
+                {
+                    crate::model::ContainedUsers::apply_to_stale_names(&user, |id, name| {
+                        osu.evict_stale_name(id, name);
+                    });
+
+                    osu.update_cache(user.user_id, &user.username);
+                }
+
+                // The cached id was correct after all so the 404 was not
+                // caused by a stale name
+                if this.reverified_id.take() == Some(user.user_id) {
+                    return Poll::Ready(Err(OsuError::NotFound));
+                }
 
                 #[cfg(feature = "metrics")]
-                // Technically, using a gauge and setting it to
-                // `osu.cache.len()` would be more correct but since
-                // `DashMap::len` is a non-trivial call, it should be fine
+                // Technically, using a gauge and setting it to the cache's
+                // length would be more correct but since the cache is not
+                // required to know its length, it should be fine
                 // to increment a counter. This works because we're only in
                 // this path if the cache did not contain the username in
                 // the first place, meaning we indeed add a new entry.
@@ -192,11 +249,26 @@ impl<T: OsuFutureData> Future for OsuFuture<T> {
                 let FromUser { data, f } = this.from_user.take().expect("missing from_user");
                 let req = f(user.user_id, data);
 
-                let next = OsuRequestStageInner::new(osu, req)?;
+                let next = OsuRequestStageInner::new(osu, req, *this.priority)?;
                 this.stage.project_replace(OsuFutureStage::Final(next));
 
                 self.poll(cx)
             }
+            Poll::Ready(ControlFlow::Break(Err(OsuError::NotFound))) if this.reverify.is_some() => {
+                let (name, user_id, osu) = this.reverify.take().expect("missing reverify");
+                *this.reverified_id = Some(user_id);
+
+                #[cfg(feature = "cache")]
+                osu.evict_from_cache(&name);
+
+                debug!(%name, "Cached name not found, fetching user again...");
+
+                let req = GetUser::create_request(UserId::Name(name), None);
+                let next = OsuRequestStageInner::new(osu, req, *this.priority)?;
+                this.stage.project_replace(OsuFutureStage::User(next));
+
+                self.poll(cx)
+            }
             Poll::Ready(ControlFlow::Break(Err(err))) => Poll::Ready(Err(err)),
             Poll::Pending => Poll::Pending,
         }
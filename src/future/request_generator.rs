@@ -1,15 +1,21 @@
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use bytes::Bytes;
 use http_body_util::Full;
 use hyper::{
-    header::{HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
-    Request as HyperRequest,
+    header::{
+        HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, IF_MODIFIED_SINCE,
+        IF_NONE_MATCH, USER_AGENT,
+    },
+    HeaderMap, Request as HyperRequest,
 };
 use url::Url;
 
 use crate::{
-    client::OsuInner,
+    client::{CacheSlot, Lookup, OsuInner, Priority, Validators},
     error::OsuError,
     request::{Method, Request},
     OsuResult,
@@ -23,8 +29,13 @@ pub(super) struct FutureRequestGenerator {
     api_version: u32,
     body: Vec<u8>,
     pub(super) attempt: u8,
-    #[cfg(feature = "metrics")]
+    pub(super) priority: Priority,
+    start: Instant,
     pub(super) route: &'static str,
+    /// Where to store the response if it should be cached
+    pub(super) cache: Option<CacheSlot>,
+    /// Validators of a stale cached response
+    validators: Option<Validators>,
 }
 
 pub(super) static MY_USER_AGENT: &str = concat!(
@@ -39,7 +50,7 @@ pub(super) const APPLICATION_JSON: &str = "application/json";
 pub(super) const X_API_VERSION: &str = "x-api-version";
 
 impl FutureRequestGenerator {
-    pub(super) fn new(osu: Arc<OsuInner>, req: Request) -> OsuResult<Self> {
+    pub(super) fn new(osu: Arc<OsuInner>, req: Request, priority: Priority) -> OsuResult<Self> {
         let Request {
             query,
             route,
@@ -49,7 +60,7 @@ impl FutureRequestGenerator {
 
         let (method, path) = route.as_parts();
 
-        let mut url = format!("https://osu.ppy.sh/api/v2/{path}");
+        let mut url = format!("{}{path}", osu.api_url);
 
         if let Some(ref query) = query {
             url.push('?');
@@ -82,11 +93,84 @@ impl FutureRequestGenerator {
             api_version,
             body: body.into_bytes(),
             attempt: 0,
-            #[cfg(feature = "metrics")]
+            priority,
+            start: Instant::now(),
             route: route.name(),
+            cache: None,
+            validators: None,
         })
     }
 
+    /// Returns the cached response if it's still fresh.
+    ///
+    /// Otherwise, prepares the request to be revalidated or cached.
+    pub(super) fn cached_response(&mut self) -> Option<Bytes> {
+        if !matches!(self.method, Method::Get) {
+            return None;
+        }
+
+        let cache = self.osu.response_cache.as_ref()?;
+        let ttl = cache.ttl(self.route)?;
+
+        let key = format!("{} {}", self.api_version, self.uri);
+        let slot = CacheSlot {
+            key: key.into(),
+            ttl,
+        };
+
+        match cache.lookup(&slot.key) {
+            Lookup::Fresh(bytes) => return Some(bytes),
+            Lookup::Stale(validators) => self.validators = Some(validators),
+            Lookup::Miss => {}
+        }
+
+        self.cache = Some(slot);
+
+        None
+    }
+
+    /// Whether the request was sent to revalidate a stale cached response.
+    pub(super) const fn is_revalidating(&self) -> bool {
+        self.validators.is_some()
+    }
+
+    /// Refresh the stale cached response after the osu!api responded with a
+    /// 304.
+    ///
+    /// Returns `None` if the response has been evicted from the cache in the
+    /// meantime. The validators are removed in that case so that the request
+    /// can be sent again to receive the full response.
+    pub(super) fn revalidate(&mut self) -> Option<Bytes> {
+        self.validators.as_ref()?;
+        let cache = self.osu.response_cache.as_ref()?;
+        let bytes = cache.revalidate(self.cache.as_ref()?);
+
+        if bytes.is_none() {
+            self.validators = None;
+        }
+
+        bytes
+    }
+
+    /// Whether the request can be sent again even though the osu!api might
+    /// have processed it already.
+    pub(super) const fn is_resendable(&self) -> bool {
+        matches!(self.method, Method::Get)
+    }
+
+    /// Returns the duration to wait before retrying or `None` if the request
+    /// should not be retried.
+    pub(super) fn retry_backoff(&mut self, headers: Option<&HeaderMap>) -> Option<Duration> {
+        let backoff = self
+            .osu
+            .retry
+            .backoff(self.attempt + 1, self.start.elapsed(), headers)?;
+
+        self.attempt += 1;
+
+        Some(backoff)
+    }
+
     pub(super) fn generate(&self) -> OsuResult<HyperRequest<Full<Bytes>>> {
         let len = self.body.len();
 
@@ -103,6 +187,16 @@ impl FutureRequestGenerator {
             req = req.header(CONTENT_TYPE, APPLICATION_JSON);
         }
 
+        if let Some(ref validators) = self.validators {
+            if let Some(ref etag) = validators.etag {
+                req = req.header(IF_NONE_MATCH, etag.clone());
+            }
+
+            if let Some(ref last_modified) = validators.last_modified {
+                req = req.header(IF_MODIFIED_SINCE, last_modified.clone());
+            }
+        }
+
         let body = Full::new(Bytes::copy_from_slice(&self.body));
 
         req.body(body).map_err(OsuError::from)
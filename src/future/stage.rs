@@ -4,42 +4,59 @@ use std::{
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
+    time::Duration,
 };
 
 #[cfg(feature = "metrics")]
 use std::time::Instant;
 
-use http_body_util::{combinators::Collect, BodyExt};
+use http_body_util::{combinators::Collect, BodyExt, Full};
 use hyper::{
-    body::{Bytes, Incoming},
-    Response as HyperResponse, StatusCode,
+    body::Bytes, HeaderMap, Request as HyperRequest, Response as HyperResponse, StatusCode,
 };
-use hyper_util::client::legacy::ResponseFuture as HyperResponseFuture;
-use leaky_bucket::{AcquireOwned, RateLimiter};
-use pin_project::pin_project;
-use tokio::time::Timeout;
+use leaky_bucket::AcquireOwned;
+use pin_project::{pin_project, pinned_drop};
+use tokio::time::{Sleep, Timeout};
 
 use crate::{
-    client::OsuInner,
+    client::{CacheSlot, OsuInner, Priority, Validators},
     error::{ApiError, OsuError},
+    middleware::{MiddlewareResponse, RequestInfo},
     prelude::UserExtended,
     request::Request,
+    transport::{ResponseBody, TransportFuture},
     OsuResult,
 };
 
 use super::request_generator::FutureRequestGenerator;
 
-#[pin_project]
+/// Progress of a [`Ratelimit`] stage through the priority gate.
+#[derive(Copy, Clone)]
+enum GateStatus {
+    /// Not polled yet
+    Idle,
+    /// Waiting for our turn to acquire with the given id
+    Waiting(u64),
+    /// Acquired from the ratelimiter
+    Passed,
+}
+
+#[pin_project(PinnedDrop)]
 struct Ratelimit {
+    gate: GateStatus,
+    #[pin]
+    acquire: Option<AcquireOwned>,
     #[pin]
-    acquire: AcquireOwned,
+    throttle: Option<Sleep>,
     generator: Option<FutureRequestGenerator>,
 }
 
 impl Ratelimit {
-    fn new(ratelimiter: Arc<RateLimiter>, generator: FutureRequestGenerator) -> Self {
+    const fn new(generator: FutureRequestGenerator) -> Self {
         Self {
-            acquire: ratelimiter.acquire_owned(1),
+            gate: GateStatus::Idle,
+            acquire: None,
+            throttle: None,
             generator: Some(generator),
         }
     }
@@ -49,10 +66,93 @@ impl Future for Ratelimit {
     type Output = FutureRequestGenerator;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        // Only join the gate once polled; otherwise futures that are created
+        // but not polled yet could be selected and block everyone else.
+        if let GateStatus::Idle = *this.gate {
+            let generator = this.generator.as_ref().expect("missing generator");
+            let gate_id = generator.osu.priority_gate.enter(generator.priority);
+            *this.gate = GateStatus::Waiting(gate_id);
+        }
+
+        if let GateStatus::Waiting(gate_id) = *this.gate {
+            let osu = &this.generator.as_ref().expect("missing generator").osu;
+
+            if this.acquire.is_none() {
+                if osu.priority_gate.poll_turn(gate_id, cx).is_pending() {
+                    return Poll::Pending;
+                }
+
+                let ratelimiter = Arc::clone(&osu.ratelimiter);
+                this.acquire.set(Some(ratelimiter.acquire_owned(1)));
+            }
+
+            let acquire = this.acquire.as_pin_mut().expect("missing acquire");
+
+            if acquire.poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+
+            osu.priority_gate.leave(gate_id);
+            *this.gate = GateStatus::Passed;
+
+            // Slow down if the osu!api signals that we're running low on
+            // our request budget
+            match osu.adaptive_ratelimit.delay() {
+                Some(delay) => this.throttle.set(Some(tokio::time::sleep(delay))),
+                None => return Poll::Ready(this.generator.take().expect("missing generator")),
+            }
+        }
+
+        match this.throttle.as_pin_mut().map(|sleep| sleep.poll(cx)) {
+            Some(Poll::Pending) => Poll::Pending,
+            _ => Poll::Ready(this.generator.take().expect("missing generator")),
+        }
+    }
+}
+
+#[pinned_drop]
+impl PinnedDrop for Ratelimit {
+    fn drop(self: Pin<&mut Self>) {
         let this = self.project();
 
-        match this.acquire.poll(cx) {
-            Poll::Ready(_) => Poll::Ready(this.generator.take().expect("missing generator")),
+        if let (GateStatus::Waiting(gate_id), Some(generator)) =
+            (*this.gate, this.generator.as_ref())
+        {
+            generator.osu.priority_gate.leave(gate_id);
+        }
+    }
+}
+
+#[pin_project]
+struct Backoff {
+    #[pin]
+    sleep: Sleep,
+    generator: Option<FutureRequestGenerator>,
+}
+
+impl Backoff {
+    fn new(backoff: Duration, generator: FutureRequestGenerator) -> Self {
+        Self {
+            sleep: tokio::time::sleep(backoff),
+            generator: Some(generator),
+        }
+    }
+}
+
+impl Future for Backoff {
+    type Output = Ratelimit;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        match this.sleep.poll(cx) {
+            Poll::Ready(()) => {
+                let generator = this.generator.take().expect("missing generator");
+
+                Poll::Ready(Ratelimit::new(generator))
+            }
             Poll::Pending => Poll::Pending,
         }
     }
@@ -61,17 +161,23 @@ impl Future for Ratelimit {
 #[pin_project]
 struct InFlight {
     #[pin]
-    future: Timeout<HyperResponseFuture>,
+    future: Timeout<TransportFuture>,
     generator: Option<FutureRequestGenerator>,
+    request: Option<Box<RequestInfo>>,
     #[cfg(feature = "metrics")]
     start: Option<Instant>,
 }
 
 impl InFlight {
-    fn new(future: HyperResponseFuture, generator: FutureRequestGenerator) -> Self {
+    fn new(
+        future: TransportFuture,
+        generator: FutureRequestGenerator,
+        request: Option<RequestInfo>,
+    ) -> Self {
         Self {
             future: tokio::time::timeout(generator.osu.timeout, future),
             generator: Some(generator),
+            request: request.map(Box::new),
             #[cfg(feature = "metrics")]
             start: None,
         }
@@ -79,7 +185,7 @@ impl InFlight {
 }
 
 enum InFlightOutput {
-    Ratelimit(Ratelimit),
+    Backoff(Backoff),
     Chunking(Chunking),
     Failed(OsuError),
 }
@@ -96,25 +202,59 @@ impl Future for InFlight {
         match this.future.poll(cx) {
             Poll::Ready(Ok(Ok(resp))) => {
                 let status = resp.status();
+                let mut generator = this.generator.take().expect("missing generator");
 
-                match status {
-                    StatusCode::NOT_FOUND => {
-                        return Poll::Ready(InFlightOutput::Failed(OsuError::NotFound))
-                    }
-                    StatusCode::SERVICE_UNAVAILABLE => {
-                        return Poll::Ready(InFlightOutput::Failed(OsuError::ServiceUnavailable {
-                            body: resp.into_body(),
-                        }))
+                generator
+                    .osu
+                    .adaptive_ratelimit
+                    .update(status, resp.headers());
+
+                // Only a 429 ensures that the osu!api did not process the
+                // request so other statuses are only retried if resending
+                // has no side effects
+                let retry = generator.osu.retry.retries_status(status)
+                    && (status == StatusCode::TOO_MANY_REQUESTS || generator.is_resendable());
+
+                if retry {
+                    if let Some(backoff) = generator.retry_backoff(Some(resp.headers())) {
+                        warn!(
+                            "Received {status} on attempt {}/{}, retrying in {backoff:?}...",
+                            generator.attempt, generator.osu.retry.max_retries,
+                        );
+
+                        return Poll::Ready(InFlightOutput::Backoff(Backoff::new(
+                            backoff, generator,
+                        )));
                     }
-                    StatusCode::TOO_MANY_REQUESTS => warn!("429 response: {resp:?}"),
-                    _ => {}
                 }
 
-                let generator = this.generator.take().expect("missing generator");
+                if status == StatusCode::TOO_MANY_REQUESTS {
+                    warn!("429 response: {resp:?}");
+                }
+
+                let mut revalidated = None;
+
+                if status == StatusCode::NOT_MODIFIED && generator.is_revalidating() {
+                    revalidated = generator.revalidate();
+
+                    // The cached response was evicted while the request was
+                    // in flight so we need to request the full response
+                    if revalidated.is_none() {
+                        debug!("Cached response got evicted, sending request again...");
+
+                        return Poll::Ready(InFlightOutput::Backoff(Backoff::new(
+                            Duration::ZERO,
+                            generator,
+                        )));
+                    }
+                }
 
                 let chunking = Chunking::new(
                     resp,
                     generator.osu,
+                    this.request.take().map(|request| *request),
+                    generator.cache.take(),
+                    revalidated,
                     #[cfg(feature = "metrics")]
                     ChunkingMetrics {
                         start,
@@ -129,25 +269,23 @@ impl Future for InFlight {
             }
             Poll::Ready(Err(_)) => {
                 let mut generator = this.generator.take().expect("missing generator");
-                let max_retries = generator.osu.retries;
 
-                if generator.attempt >= max_retries {
-                    return Poll::Ready(InFlightOutput::Failed(OsuError::RequestTimeout));
-                }
+                let backoff = if generator.is_resendable() {
+                    generator.retry_backoff(None)
+                } else {
+                    None
+                };
 
-                generator.attempt += 1;
+                let Some(backoff) = backoff else {
+                    return Poll::Ready(InFlightOutput::Failed(OsuError::RequestTimeout));
+                };
 
                 warn!(
-                    "Timed out on attempt {}/{max_retries}, retrying...",
-                    generator.attempt
+                    "Timed out on attempt {}/{}, retrying in {backoff:?}...",
+                    generator.attempt, generator.osu.retry.max_retries,
                 );
 
-                let ratelimiter = Arc::clone(&generator.osu.ratelimiter);
-
-                Poll::Ready(InFlightOutput::Ratelimit(Ratelimit::new(
-                    ratelimiter,
-                    generator,
-                )))
+                Poll::Ready(InFlightOutput::Backoff(Backoff::new(backoff, generator)))
             }
             Poll::Pending => Poll::Pending,
         }
@@ -157,13 +295,31 @@ impl Future for InFlight {
 #[pin_project]
 pub(super) struct Chunking {
     #[pin]
-    future: Collect<Incoming>,
+    future: Collect<ResponseBody>,
     status: StatusCode,
     osu: Arc<OsuInner>,
+    /// Only present if the response should be passed to middlewares
+    middleware: Option<Box<MiddlewareContext>>,
+    /// Only present if the response should be cached
+    cache: Option<Box<CacheContext>>,
     #[cfg(feature = "metrics")]
     metrics: ChunkingMetrics,
 }
 
+struct MiddlewareContext {
+    request: RequestInfo,
+    headers: HeaderMap,
+    /// Amount of middlewares that handled the request
+    handled_by: usize,
+}
+
+struct CacheContext {
+    slot: CacheSlot,
+    validators: Validators,
+    /// The refreshed cached response if the osu!api responded with a 304
+    revalidated: Option<Bytes>,
+}
+
 #[cfg(feature = "metrics")]
 pub(super) struct ChunkingMetrics {
     pub(super) start: Instant,
@@ -171,21 +327,83 @@ pub(super) struct ChunkingMetrics {
 }
 
 impl Chunking {
+    /// `request` is `None` if the response should not be passed to
+    /// middlewares, e.g. for token requests.
     pub(super) fn new(
-        resp: HyperResponse<Incoming>,
+        resp: HyperResponse<ResponseBody>,
+        osu: Arc<OsuInner>,
+        request: Option<RequestInfo>,
+        cache: Option<CacheSlot>,
+        revalidated: Option<Bytes>,
+        #[cfg(feature = "metrics")] metrics: ChunkingMetrics,
+    ) -> Self {
+        let (parts, body) = resp.into_parts();
+
+        let cache = cache.map(|slot| {
+            Box::new(CacheContext {
+                slot,
+                validators: Validators::from_headers(&parts.headers),
+                revalidated,
+            })
+        });
+
+        let middleware = request.map(|request| {
+            Box::new(MiddlewareContext {
+                request,
+                headers: parts.headers,
+                handled_by: osu.middlewares.len(),
+            })
+        });
+
+        Self {
+            status: parts.status,
+            future: body.collect(),
+            osu,
+            middleware,
+            cache,
+            #[cfg(feature = "metrics")]
+            metrics,
+        }
+    }
+
+    /// A [`Chunking`] stage for a response that was provided by a middleware
+    /// instead of the transport.
+    ///
+    /// `handled_by` is the amount of middlewares up to and including the one
+    /// that provided the response.
+    fn ready(
+        resp: MiddlewareResponse,
         osu: Arc<OsuInner>,
+        request: RequestInfo,
+        handled_by: usize,
         #[cfg(feature = "metrics")] metrics: ChunkingMetrics,
     ) -> Self {
+        let MiddlewareResponse {
+            status,
+            headers,
+            bytes,
+        } = resp;
+
         Self {
-            status: resp.status(),
-            future: resp.into_body().collect(),
+            status,
+            future: full_body(bytes).collect(),
             osu,
+            middleware: Some(Box::new(MiddlewareContext {
+                request,
+                headers,
+                handled_by,
+            })),
+            cache: None,
             #[cfg(feature = "metrics")]
             metrics,
         }
     }
 }
 
+fn full_body(bytes: Bytes) -> ResponseBody {
+    Full::new(bytes).map_err(|never| match never {}).boxed()
+}
+
 impl Future for Chunking {
     type Output = OsuResult<(Bytes, Arc<OsuInner>)>;
 
@@ -207,24 +425,75 @@ impl Future for Chunking {
         // let text = String::from_utf8_lossy(&bytes);
         // println!("Response:\n{text}");
 
-        let status = *this.status;
         let osu = Arc::clone(this.osu);
+        let (status, bytes) = match this.middleware.take() {
+            Some(ctx) => {
+                let MiddlewareContext {
+                    request,
+                    headers,
+                    handled_by,
+                } = *ctx;
+
+                let mut resp = MiddlewareResponse {
+                    status: *this.status,
+                    headers,
+                    bytes,
+                };
+
+                for middleware in osu.middlewares[..handled_by].iter().rev() {
+                    middleware.on_response(&request, &mut resp);
+                }
+
+                (resp.status, resp.bytes)
+            }
+            None => (*this.status, bytes),
+        };
 
-        if status.is_success() {
-            return Poll::Ready(Ok((bytes, osu)));
+        if let Some(ctx) = this.cache.take() {
+            let CacheContext {
+                slot,
+                validators,
+                revalidated,
+            } = *ctx;
+
+            if let Some(ref cache) = osu.response_cache {
+                if status == StatusCode::NOT_MODIFIED {
+                    if let Some(bytes) = revalidated {
+                        return Poll::Ready(Ok((bytes, osu)));
+                    }
+                } else if status.is_success() {
+                    cache.insert(&slot, bytes.clone(), validators);
+                }
+            }
         }
 
-        let err = match serde_json::from_slice::<ApiError>(&bytes) {
-            Ok(source) => OsuError::Response {
-                bytes,
-                source,
-                status,
-            },
-            Err(source) => OsuError::Parsing { bytes, source },
+        let res = match status {
+            StatusCode::NOT_FOUND => Err(OsuError::NotFound),
+            StatusCode::SERVICE_UNAVAILABLE => Err(OsuError::ServiceUnavailable {
+                body: full_body(bytes),
+            }),
+            _ => parse_response(status, bytes),
         };
 
-        Poll::Ready(Err(err))
+        Poll::Ready(res.map(|bytes| (bytes, osu)))
+    }
+}
+
+fn parse_response(status: StatusCode, bytes: Bytes) -> OsuResult<Bytes> {
+    if status.is_success() {
+        return Ok(bytes);
     }
+
+    let err = match serde_json::from_slice::<ApiError>(&bytes) {
+        Ok(source) => OsuError::Response {
+            bytes,
+            source,
+            status,
+        },
+        Err(source) => OsuError::Parsing { bytes, source },
+    };
+
+    Err(err)
 }
 
 #[pin_project(project = StageInnerProject, project_replace = StageInnerReplace)]
@@ -235,16 +504,61 @@ impl Future for Chunking {
 )]
 pub(super) enum OsuRequestStageInner {
     Ratelimit(#[pin] Ratelimit),
+    Backoff(#[pin] Backoff),
     InFlight(#[pin] InFlight),
     Chunking(#[pin] Chunking),
+    /// A fresh response from the response cache
+    Cached(Option<(Bytes, Arc<OsuInner>)>),
 }
 
 impl OsuRequestStageInner {
-    pub(super) fn new(osu: Arc<OsuInner>, req: Request) -> OsuResult<Self> {
-        let ratelimiter = Arc::clone(&osu.ratelimiter);
-        let generator = FutureRequestGenerator::new(osu, req)?;
+    pub(super) fn new(osu: Arc<OsuInner>, req: Request, priority: Priority) -> OsuResult<Self> {
+        let mut generator = FutureRequestGenerator::new(osu, req, priority)?;
 
-        Ok(Self::Ratelimit(Ratelimit::new(ratelimiter, generator)))
+        // Cached responses don't need to wait for the ratelimiter
+        if let Some(bytes) = generator.cached_response() {
+            return Ok(Self::Cached(Some((bytes, generator.osu))));
+        }
+
+        Ok(Self::Ratelimit(Ratelimit::new(generator)))
+    }
+
+    /// Pass the request through the middlewares and then to the transport
+    /// unless a middleware responded already.
+    fn send(mut req: HyperRequest<Full<Bytes>>, generator: FutureRequestGenerator) -> Self {
+        let osu = &generator.osu;
+
+        let short_circuit = osu
+            .middlewares
+            .iter()
+            .enumerate()
+            .find_map(|(i, middleware)| Some((i, middleware.on_request(&mut req)?)));
+
+        // Only keep track of the request if middlewares need it later on
+        let request = (!osu.middlewares.is_empty()).then(|| RequestInfo {
+            method: req.method().clone(),
+            uri: req.uri().clone(),
+        });
+
+        if let Some((i, resp)) = short_circuit {
+            let request = request.expect("middlewares are present");
+
+            return Self::Chunking(Chunking::ready(
+                resp,
+                Arc::clone(osu),
+                request,
+                i + 1,
+                #[cfg(feature = "metrics")]
+                ChunkingMetrics {
+                    start: Instant::now(),
+                    route: generator.route,
+                },
+            ));
+        }
+
+        let future = osu.http.request(req);
+
+        Self::InFlight(InFlight::new(future, generator, request))
     }
 }
 
@@ -255,19 +569,17 @@ impl Future for OsuRequestStageInner {
         match self.project() {
             StageInnerProject::Ratelimit(ratelimit) => match ratelimit.poll(cx) {
                 Poll::Ready(generator) => match generator.generate() {
-                    Ok(req) => {
-                        let future = generator.osu.http.request(req);
-                        let in_flight = InFlight::new(future, generator);
-
-                        Poll::Ready(ControlFlow::Continue(Self::InFlight(in_flight)))
-                    }
+                    Ok(req) => Poll::Ready(ControlFlow::Continue(Self::send(req, generator))),
                     Err(err) => Poll::Ready(ControlFlow::Break(Err(err))),
                 },
                 Poll::Pending => Poll::Pending,
             },
+            StageInnerProject::Backoff(backoff) => backoff
+                .poll(cx)
+                .map(|ratelimit| ControlFlow::Continue(Self::Ratelimit(ratelimit))),
             StageInnerProject::InFlight(in_flight) => match in_flight.poll(cx) {
-                Poll::Ready(InFlightOutput::Ratelimit(ratelimit)) => {
-                    Poll::Ready(ControlFlow::Continue(Self::Ratelimit(ratelimit)))
+                Poll::Ready(InFlightOutput::Backoff(backoff)) => {
+                    Poll::Ready(ControlFlow::Continue(Self::Backoff(backoff)))
                 }
                 Poll::Ready(InFlightOutput::Chunking(chunking)) => {
                     Poll::Ready(ControlFlow::Continue(Self::Chunking(chunking)))
@@ -278,6 +590,11 @@ impl Future for OsuRequestStageInner {
                 Poll::Pending => Poll::Pending,
             },
             StageInnerProject::Chunking(chunking) => chunking.poll(cx).map(ControlFlow::Break),
+            StageInnerProject::Cached(cached) => {
+                let cached = cached.take().expect("cached response already taken");
+
+                Poll::Ready(ControlFlow::Break(Ok(cached)))
+            }
         }
     }
 }
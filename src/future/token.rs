@@ -14,7 +14,6 @@ use hyper::{
     header::{ACCEPT, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
     Request as HyperRequest, StatusCode,
 };
-use hyper_util::client::legacy::ResponseFuture as HyperResponseFuture;
 use pin_project::pin_project;
 use tokio::time::Timeout;
 
@@ -22,6 +21,7 @@ use crate::{
     client::{Authorization, OsuInner, Scopes, TokenResponse},
     error::OsuError,
     request::JsonBody,
+    transport::TransportFuture,
     OsuResult,
 };
 
@@ -31,6 +31,7 @@ use super::{
 };
 
 struct TokenRequestGenerator {
+    url: String,
     body: Vec<u8>,
 }
 
@@ -40,6 +41,7 @@ impl TokenRequestGenerator {
         body.push_str("client_secret", &osu.client_secret);
 
         Self {
+            url: format!("{}token", osu.oauth_url),
             body: body.into_bytes(),
         }
     }
@@ -47,9 +49,8 @@ impl TokenRequestGenerator {
     fn generate(self) -> OsuResult<HyperRequest<Full<Bytes>>> {
         let len = self.body.len();
         let body = Full::new(Bytes::from(self.body));
-        let url = "https://osu.ppy.sh/oauth/token";
 
-        HyperRequest::post(url)
+        HyperRequest::post(self.url)
             .header(USER_AGENT, MY_USER_AGENT)
             .header(ACCEPT, APPLICATION_JSON)
             .header(CONTENT_TYPE, APPLICATION_JSON)
@@ -62,14 +63,14 @@ impl TokenRequestGenerator {
 #[pin_project]
 struct TokenInFlight {
     #[pin]
-    future: Timeout<HyperResponseFuture>,
+    future: Timeout<TransportFuture>,
     osu: Option<Arc<OsuInner>>,
     #[cfg(feature = "metrics")]
     start: Option<Instant>,
 }
 
 impl TokenInFlight {
-    fn new(future: HyperResponseFuture, osu: Arc<OsuInner>) -> Self {
+    fn new(future: TransportFuture, osu: Arc<OsuInner>) -> Self {
         Self {
             future: tokio::time::timeout(osu.timeout, future),
             osu: Some(osu),
@@ -105,6 +106,9 @@ impl Future for TokenInFlight {
                 Poll::Ready(Ok(Chunking::new(
                     resp,
                     osu,
+                    None,
+                    None,
+                    None,
                     #[cfg(feature = "metrics")]
                     super::stage::ChunkingMetrics {
                         start,
@@ -18,7 +18,7 @@ pub trait OsuFutureData: IntoFuture<Output = OsuResult<Self::OsuOutput>> {
     /// Post processing will convert [`Self::FromBytes`] into this type.
     type OsuOutput: ContainedUsers;
     /// Auxiliary data to create a request from a user.
-    type FromUserData;
+    type FromUserData: Clone;
     /// Auxiliary data used for post processing.
     type PostProcessData;
 }
@@ -116,6 +116,7 @@
 //! | `metrics`     | Uses the global metrics registry to store response time for each endpoint | [`metrics`]
 //! | `replay`      | Enables the method `Osu::replay` to parse a replay. Note that `Osu::replay_raw` is available without this feature but provides raw bytes instead of a parsed replay | [`osu-db`]
 //! | `local_oauth` | Enables the method `OsuBuilder::with_local_authorization` to perform the full OAuth procedure | `tokio/net` feature
+//! | `testing`     | Enables the `testing` module containing a local mock server of the osu!api to test requests offline | `hyper/server` feature
 //!
 //! [osu!api v2]: https://osu.ppy.sh/docs/index.html
 //! [`rosu`]: https://github.com/MaxOhn/rosu
@@ -148,18 +149,34 @@ mod client;
 mod future;
 mod routing;
 
+/// Username cache backends
+#[cfg(feature = "cache")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cache")))]
+pub mod cache;
+
 /// Errors types
 pub mod error;
 
 /// All available data types provided by the api
 pub mod model;
 
+/// Hooks to inspect and modify requests and responses
+pub mod middleware;
+
 /// Request related types to fetch from endpoints
 pub mod request;
 
+/// The HTTP client used to send requests
+pub mod transport;
+
+/// Mock server to test requests without the osu!api
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
+
 mod metrics;
 
-pub use self::client::{Osu, OsuBuilder};
+pub use self::client::{Osu, OsuBuilder, Priority, RatelimitBudget, ResponseCache, RetryPolicy};
 
 #[macro_use]
 extern crate tracing;
@@ -181,7 +198,9 @@ pub mod prelude {
         error::OsuError,
         model::{
             beatmap::*,
+            chat::*,
             comments::*,
+            cursor::*,
             event::*,
             forum::*,
             kudosu::*,
@@ -189,14 +208,15 @@ pub mod prelude {
             mods::{generated_mods::*, Acronym, GameMods, GameModsIntermode, GameModsLegacy},
             news::*,
             ranking::*,
+            room::*,
             score::*,
             seasonal_backgrounds::*,
             user::*,
             wiki::*,
-            GameMode, Grade,
+            BulkLookup, GameMode, Grade,
         },
         request::UserId,
-        Osu, OsuBuilder, OsuResult,
+        Osu, OsuBuilder, OsuResult, Priority, RatelimitBudget, ResponseCache, RetryPolicy,
     };
 
     pub use hyper::StatusCode;
@@ -0,0 +1,85 @@
+use bytes::Bytes;
+use http_body_util::Full;
+use hyper::{HeaderMap, Method, Request, StatusCode, Uri};
+
+/// Hooks to observe or modify the traffic between the client and the
+/// osu!api.
+///
+/// Middlewares are registered through [`OsuBuilder::middleware`] and apply to
+/// all API requests, including retries and the intermediate user requests
+/// when requesting by username, but not to token requests.
+///
+/// [`Middleware::on_request`] is called in the order the middlewares were
+/// registered while [`Middleware::on_response`] is called in reverse order.
+///
+/// ## Example
+///
+/// ```
+/// use rosu_v2::middleware::{Middleware, MiddlewareResponse, RequestInfo};
+///
+/// struct AuditLog;
+///
+/// impl Middleware for AuditLog {
+///     fn on_response(&self, req: &RequestInfo, resp: &mut MiddlewareResponse) {
+///         println!("{} {} -> {} ({} bytes)", req.method, req.uri, resp.status, resp.bytes.len());
+///     }
+/// }
+/// ```
+///
+/// [`OsuBuilder::middleware`]: crate::OsuBuilder::middleware
+pub trait Middleware: Send + Sync + 'static {
+    /// Inspect or modify a request right before it is sent.
+    ///
+    /// Called once for every attempt, including retries.
+    ///
+    /// Returning `Some` skips sending the request and the given response is
+    /// used instead. Subsequent middlewares won't see the request nor the
+    /// response; only this middleware and the ones registered before it get
+    /// to handle the response.
+    fn on_request(&self, req: &mut Request<Full<Bytes>>) -> Option<MiddlewareResponse> {
+        let _ = req;
+
+        None
+    }
+
+    /// Inspect or modify a response before it is deserialized.
+    ///
+    /// Only the response of the final attempt is observed. Responses that
+    /// lead to a retry, e.g. a 503 or a timeout, are not passed to
+    /// middlewares.
+    fn on_response(&self, req: &RequestInfo, resp: &mut MiddlewareResponse) {
+        let _ = (req, resp);
+    }
+}
+
+/// A fully received response as seen by [`Middleware`]s.
+#[derive(Clone, Debug)]
+pub struct MiddlewareResponse {
+    /// Status of the response
+    pub status: StatusCode,
+    /// Headers of the response
+    pub headers: HeaderMap,
+    /// Body of the response
+    pub bytes: Bytes,
+}
+
+impl MiddlewareResponse {
+    /// Create a new response with the given status and body, and no headers.
+    pub fn new(status: StatusCode, bytes: impl Into<Bytes>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            bytes: bytes.into(),
+        }
+    }
+}
+
+/// Information about the request whose response is passed to
+/// [`Middleware::on_response`].
+#[derive(Clone, Debug)]
+pub struct RequestInfo {
+    /// Method of the request
+    pub method: Method,
+    /// URI of the request, including its query
+    pub uri: Uri,
+}
@@ -13,11 +13,25 @@ use time::OffsetDateTime;
 use crate::{
     error::ParsingError,
     prelude::{CountryCode, OsuError, UserStatisticsModes, Username},
-    request::{GetBeatmapDifficultyAttributes, GetUser},
+    request::{
+        BeatmapsetDiscussionPostsParams, BeatmapsetDiscussionVotesParams,
+        BeatmapsetDiscussionsParams, GetBeatmapDifficultyAttributes, GetBeatmapPacks,
+        GetBeatmapsetDiscussionPosts, GetBeatmapsetDiscussionVotes, GetBeatmapsetDiscussions,
+        GetBeatmapsetSearch, GetUser, PageFuture, Paginated, Paginator,
+    },
     Osu, OsuResult,
 };
 
-use super::{score::Score, serde_util, user::User, CacheUserFn, ContainedUsers, GameMode};
+use super::{
+    cursor::{
+        BeatmapPacksCursor, BeatmapsetDiscussionPostsCursor, BeatmapsetDiscussionVotesCursor,
+        BeatmapsetDiscussionsCursor, BeatmapsetSearchCursor,
+    },
+    score::Score,
+    serde_util,
+    user::User,
+    CacheUserFn, ContainedUsers, GameMode,
+};
 
 #[derive(Clone, Debug, Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -212,6 +226,172 @@ pub enum GameModeAttributes {
     },
 }
 
+/// A collection of mapsets that can be downloaded and completed together.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct BeatmapPack {
+    pub author: String,
+    #[serde(with = "serde_util::datetime")]
+    pub date: OffsetDateTime,
+    pub name: String,
+    /// Whether difficulty reduction mods may not be used to clear the pack
+    pub no_diff_reduction: bool,
+    /// Mode of the pack's maps, `None` if the pack contains multiple modes
+    #[serde(
+        default,
+        rename = "ruleset_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub mode: Option<GameMode>,
+    /// Identifier of the pack, e.g. `S1` or `T42`
+    pub tag: String,
+    /// Download url of the pack
+    pub url: String,
+    /// Only available when requesting a single pack
+    #[serde(
+        default,
+        rename = "beatmapsets",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub mapsets: Option<Vec<Beatmapset>>,
+    /// Only available when requesting a single pack with a client that was
+    /// authorized through a user
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_completion_data: Option<BeatmapPackUserCompletion>,
+}
+
+impl ContainedUsers for BeatmapPack {
+    fn apply_to_users(&self, f: impl CacheUserFn) {
+        self.mapsets.apply_to_users(f);
+    }
+}
+
+/// A page of [`BeatmapPack`]s.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct BeatmapPacks {
+    #[serde(rename = "beatmap_packs")]
+    pub packs: Vec<BeatmapPack>,
+    #[serde(
+        default,
+        rename = "cursor_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub(crate) cursor: Option<BeatmapPacksCursor>,
+    #[serde(skip)]
+    pub(crate) kind: Option<BeatmapPackType>,
+}
+
+impl BeatmapPacks {
+    /// Returns whether there is a next page of packs, retrievable via
+    /// [`get_next`](BeatmapPacks::get_next).
+    #[inline]
+    pub const fn has_more(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    /// The cursor to request the next page of packs, if available.
+    ///
+    /// Can be stored and passed to [`GetBeatmapPacks::cursor`] later on.
+    #[inline]
+    pub const fn cursor(&self) -> Option<&BeatmapPacksCursor> {
+        self.cursor.as_ref()
+    }
+
+    /// If [`has_more`](BeatmapPacks::has_more) is true, the API can provide
+    /// the next set of packs and this method will request them. Otherwise,
+    /// this method returns `None`.
+    #[inline]
+    pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<BeatmapPacks>> {
+        Some(self.next_request(osu)?.await)
+    }
+
+    /// Turn these packs into a [`Stream`](futures::Stream) of all packs of the
+    /// same type, including those on subsequent pages.
+    #[inline]
+    pub fn into_stream(self, osu: &Osu) -> Paginator<'_, Self> {
+        Paginator::new(osu, self)
+    }
+
+    fn next_request<'a>(&self, osu: &'a Osu) -> Option<GetBeatmapPacks<'a>> {
+        let mut req = osu.beatmap_packs().cursor(self.cursor.clone()?);
+
+        if let Some(kind) = self.kind {
+            req = req.kind(kind);
+        }
+
+        Some(req)
+    }
+}
+
+impl Paginated for BeatmapPacks {
+    type Item = BeatmapPack;
+
+    fn next_page<'a>(&self, osu: &'a Osu) -> Option<PageFuture<'a, Self>> {
+        let req = self.next_request(osu)?;
+
+        Some(Box::pin(async move { req.await }))
+    }
+
+    fn items(&self) -> &[Self::Item] {
+        &self.packs
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.packs
+    }
+}
+
+impl ContainedUsers for BeatmapPacks {
+    fn apply_to_users(&self, f: impl CacheUserFn) {
+        self.packs.apply_to_users(f);
+    }
+}
+
+/// The type of a [`BeatmapPack`]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BeatmapPackType {
+    #[default]
+    Standard,
+    Featured,
+    Tournament,
+    Loved,
+    Chart,
+    Theme,
+    Artist,
+}
+
+impl BeatmapPackType {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::Featured => "featured",
+            Self::Tournament => "tournament",
+            Self::Loved => "loved",
+            Self::Chart => "chart",
+            Self::Theme => "theme",
+            Self::Artist => "artist",
+        }
+    }
+}
+
+impl serde::Serialize for BeatmapPackType {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+/// Completion of a [`BeatmapPack`] by the current user
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct BeatmapPackUserCompletion {
+    /// Whether all mapsets of the pack have been completed
+    pub completed: bool,
+    /// Ids of the completed mapsets
+    #[serde(rename = "beatmapset_ids")]
+    pub completed_mapset_ids: Vec<u32>,
+}
+
 /// Represents a beatmapset. This extends [`Beatmapset`] with additional attributes.
 #[derive(Clone, Debug, Deserialize)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -767,6 +947,384 @@ impl PartialEq for BeatmapsetDiscussion {
 
 impl Eq for BeatmapsetDiscussion {}
 
+/// Which mapsets to consider when requesting [`BeatmapsetDiscussions`]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BeatmapsetDiscussionMapsetStatus {
+    #[default]
+    All,
+    Ranked,
+    Qualified,
+    Disqualified,
+    NeverQualified,
+}
+
+impl BeatmapsetDiscussionMapsetStatus {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Ranked => "ranked",
+            Self::Qualified => "qualified",
+            Self::Disqualified => "disqualified",
+            Self::NeverQualified => "never_qualified",
+        }
+    }
+}
+
+impl serde::Serialize for BeatmapsetDiscussionMapsetStatus {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+/// The message type of a [`BeatmapsetDiscussion`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BeatmapsetDiscussionMessageType {
+    Suggestion,
+    Problem,
+    MapperNote,
+    Praise,
+    Hype,
+    Review,
+}
+
+impl BeatmapsetDiscussionMessageType {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Suggestion => "suggestion",
+            Self::Problem => "problem",
+            Self::MapperNote => "mapper_note",
+            Self::Praise => "praise",
+            Self::Hype => "hype",
+            Self::Review => "review",
+        }
+    }
+}
+
+impl serde::Serialize for BeatmapsetDiscussionMessageType {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+/// How to sort beatmapset discussions, posts, and votes
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BeatmapsetDiscussionSort {
+    IdAscending,
+    #[default]
+    IdDescending,
+}
+
+impl BeatmapsetDiscussionSort {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::IdAscending => "id_asc",
+            Self::IdDescending => "id_desc",
+        }
+    }
+}
+
+impl serde::Serialize for BeatmapsetDiscussionSort {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+/// A page of [`BeatmapsetDiscussion`]s.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct BeatmapsetDiscussions {
+    #[serde(rename = "beatmaps")]
+    pub maps: Vec<Beatmap>,
+    pub discussions: Vec<BeatmapsetDiscussion>,
+    /// Discussions that are referenced by `discussions`, e.g. their parents
+    #[serde(default)]
+    pub included_discussions: Vec<BeatmapsetDiscussion>,
+    pub reviews_config: BeatmapsetReviewsConfig,
+    pub users: Vec<User>,
+    #[serde(
+        default,
+        rename = "cursor_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub(crate) cursor: Option<BeatmapsetDiscussionsCursor>,
+    #[serde(skip)]
+    pub(crate) params: Option<BeatmapsetDiscussionsParams>,
+}
+
+impl BeatmapsetDiscussions {
+    /// Returns whether there is a next page of discussions, retrievable via
+    /// [`get_next`](BeatmapsetDiscussions::get_next).
+    #[inline]
+    pub const fn has_more(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    /// The cursor to request the next page of discussions, if available.
+    ///
+    /// Can be stored and passed to [`GetBeatmapsetDiscussions::cursor`] later
+    /// on.
+    #[inline]
+    pub const fn cursor(&self) -> Option<&BeatmapsetDiscussionsCursor> {
+        self.cursor.as_ref()
+    }
+
+    /// If [`has_more`](BeatmapsetDiscussions::has_more) is true, the API can
+    /// provide the next set of discussions and this method will request them.
+    /// Otherwise, this method returns `None`.
+    #[inline]
+    pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<BeatmapsetDiscussions>> {
+        Some(self.next_request(osu)?.await)
+    }
+
+    /// Turn these discussions into a [`Stream`](futures::Stream) of all
+    /// discussions with the same filters, including those on subsequent pages.
+    #[inline]
+    pub fn into_stream(self, osu: &Osu) -> Paginator<'_, Self> {
+        Paginator::new(osu, self)
+    }
+
+    fn next_request<'a>(&self, osu: &'a Osu) -> Option<GetBeatmapsetDiscussions<'a>> {
+        let cursor = self.cursor.clone()?;
+        let params = self.params.clone().unwrap_or_default();
+
+        Some(GetBeatmapsetDiscussions::with_params(osu, params).cursor(cursor))
+    }
+}
+
+impl Paginated for BeatmapsetDiscussions {
+    type Item = BeatmapsetDiscussion;
+
+    fn next_page<'a>(&self, osu: &'a Osu) -> Option<PageFuture<'a, Self>> {
+        let req = self.next_request(osu)?;
+
+        Some(Box::pin(async move { req.await }))
+    }
+
+    fn items(&self) -> &[Self::Item] {
+        &self.discussions
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.discussions
+    }
+}
+
+impl ContainedUsers for BeatmapsetDiscussions {
+    fn apply_to_users(&self, f: impl CacheUserFn) {
+        self.maps.apply_to_users(f);
+        self.users.apply_to_users(f);
+    }
+}
+
+/// A page of [`BeatmapsetPost`]s.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct BeatmapsetDiscussionPosts {
+    #[serde(rename = "beatmapsets")]
+    pub mapsets: Vec<Beatmapset>,
+    pub posts: Vec<BeatmapsetPost>,
+    pub users: Vec<User>,
+    #[serde(
+        default,
+        rename = "cursor_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub(crate) cursor: Option<BeatmapsetDiscussionPostsCursor>,
+    #[serde(skip)]
+    pub(crate) params: Option<BeatmapsetDiscussionPostsParams>,
+}
+
+impl BeatmapsetDiscussionPosts {
+    /// Returns whether there is a next page of posts, retrievable via
+    /// [`get_next`](BeatmapsetDiscussionPosts::get_next).
+    #[inline]
+    pub const fn has_more(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    /// The cursor to request the next page of posts, if available.
+    ///
+    /// Can be stored and passed to [`GetBeatmapsetDiscussionPosts::cursor`]
+    /// later on.
+    #[inline]
+    pub const fn cursor(&self) -> Option<&BeatmapsetDiscussionPostsCursor> {
+        self.cursor.as_ref()
+    }
+
+    /// If [`has_more`](BeatmapsetDiscussionPosts::has_more) is true, the API
+    /// can provide the next set of posts and this method will request them.
+    /// Otherwise, this method returns `None`.
+    #[inline]
+    pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<BeatmapsetDiscussionPosts>> {
+        Some(self.next_request(osu)?.await)
+    }
+
+    /// Turn these posts into a [`Stream`](futures::Stream) of all posts with
+    /// the same filters, including those on subsequent pages.
+    #[inline]
+    pub fn into_stream(self, osu: &Osu) -> Paginator<'_, Self> {
+        Paginator::new(osu, self)
+    }
+
+    fn next_request<'a>(&self, osu: &'a Osu) -> Option<GetBeatmapsetDiscussionPosts<'a>> {
+        let cursor = self.cursor.clone()?;
+        let params = self.params.clone().unwrap_or_default();
+
+        Some(GetBeatmapsetDiscussionPosts::with_params(osu, params).cursor(cursor))
+    }
+}
+
+impl Paginated for BeatmapsetDiscussionPosts {
+    type Item = BeatmapsetPost;
+
+    fn next_page<'a>(&self, osu: &'a Osu) -> Option<PageFuture<'a, Self>> {
+        let req = self.next_request(osu)?;
+
+        Some(Box::pin(async move { req.await }))
+    }
+
+    fn items(&self) -> &[Self::Item] {
+        &self.posts
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.posts
+    }
+}
+
+impl ContainedUsers for BeatmapsetDiscussionPosts {
+    fn apply_to_users(&self, f: impl CacheUserFn) {
+        self.mapsets.apply_to_users(f);
+        self.users.apply_to_users(f);
+    }
+}
+
+/// The type of a [`BeatmapsetPost`]
+/// to filter for when requesting [`BeatmapsetDiscussionPosts`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BeatmapsetPostType {
+    /// The starting post of a discussion
+    First,
+    Reply,
+    /// A post generated by the system, e.g. a resolution
+    System,
+}
+
+impl BeatmapsetPostType {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::First => "first",
+            Self::Reply => "reply",
+            Self::System => "system",
+        }
+    }
+}
+
+impl serde::Serialize for BeatmapsetPostType {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+/// A vote on a [`BeatmapsetDiscussion`]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct BeatmapsetDiscussionVote {
+    #[serde(rename = "id")]
+    pub vote_id: u64,
+    #[serde(rename = "beatmapset_discussion_id")]
+    pub discussion_id: u64,
+    pub user_id: u32,
+    /// Either `1` or `-1`
+    pub score: i32,
+    #[serde(with = "serde_util::datetime")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "serde_util::datetime")]
+    pub updated_at: OffsetDateTime,
+}
+
+/// A page of [`BeatmapsetDiscussionVote`]s.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct BeatmapsetDiscussionVotes {
+    /// The discussions that were voted on
+    pub discussions: Vec<BeatmapsetDiscussion>,
+    pub votes: Vec<BeatmapsetDiscussionVote>,
+    pub users: Vec<User>,
+    #[serde(
+        default,
+        rename = "cursor_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub(crate) cursor: Option<BeatmapsetDiscussionVotesCursor>,
+    #[serde(skip)]
+    pub(crate) params: Option<BeatmapsetDiscussionVotesParams>,
+}
+
+impl BeatmapsetDiscussionVotes {
+    /// Returns whether there is a next page of votes, retrievable via
+    /// [`get_next`](BeatmapsetDiscussionVotes::get_next).
+    #[inline]
+    pub const fn has_more(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    /// The cursor to request the next page of votes, if available.
+    ///
+    /// Can be stored and passed to [`GetBeatmapsetDiscussionVotes::cursor`]
+    /// later on.
+    #[inline]
+    pub const fn cursor(&self) -> Option<&BeatmapsetDiscussionVotesCursor> {
+        self.cursor.as_ref()
+    }
+
+    /// If [`has_more`](BeatmapsetDiscussionVotes::has_more) is true, the API
+    /// can provide the next set of votes and this method will request them.
+    /// Otherwise, this method returns `None`.
+    #[inline]
+    pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<BeatmapsetDiscussionVotes>> {
+        Some(self.next_request(osu)?.await)
+    }
+
+    /// Turn these votes into a [`Stream`](futures::Stream) of all votes with
+    /// the same filters, including those on subsequent pages.
+    #[inline]
+    pub fn into_stream(self, osu: &Osu) -> Paginator<'_, Self> {
+        Paginator::new(osu, self)
+    }
+
+    fn next_request<'a>(&self, osu: &'a Osu) -> Option<GetBeatmapsetDiscussionVotes<'a>> {
+        let cursor = self.cursor.clone()?;
+        let params = self.params.clone().unwrap_or_default();
+
+        Some(GetBeatmapsetDiscussionVotes::with_params(osu, params).cursor(cursor))
+    }
+}
+
+impl Paginated for BeatmapsetDiscussionVotes {
+    type Item = BeatmapsetDiscussionVote;
+
+    fn next_page<'a>(&self, osu: &'a Osu) -> Option<PageFuture<'a, Self>> {
+        let req = self.next_request(osu)?;
+
+        Some(Box::pin(async move { req.await }))
+    }
+
+    fn items(&self) -> &[Self::Item] {
+        &self.votes
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.votes
+    }
+}
+
+impl ContainedUsers for BeatmapsetDiscussionVotes {
+    fn apply_to_users(&self, f: impl CacheUserFn) {
+        self.users.apply_to_users(f);
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[serde(rename_all = "snake_case", tag = "type")]
@@ -1299,7 +1857,7 @@ impl<'de> Deserialize<'de> for BeatmapsetSearchParameters {
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct BeatmapsetSearchResult {
     #[cfg_attr(feature = "serialize", serde(rename = "cursor_string"))]
-    cursor: Option<Box<str>>,
+    cursor: Option<BeatmapsetSearchCursor>,
     /// All mapsets of the current page
     #[cfg_attr(feature = "serialize", serde(rename(serialize = "beatmapsets")))]
     pub mapsets: Vec<BeatmapsetExtended>,
@@ -1317,13 +1875,35 @@ impl BeatmapsetSearchResult {
         self.cursor.is_some()
     }
 
+    /// The cursor to request the next page of search results, if available.
+    ///
+    /// Can be stored and passed to [`GetBeatmapsetSearch::cursor`] later on.
+    #[inline]
+    pub const fn cursor(&self) -> Option<&BeatmapsetSearchCursor> {
+        self.cursor.as_ref()
+    }
+
     /// If [`has_more`](BeatmapsetSearchResult::has_more) is true, the API can provide
     /// the next set of search results and this method will request them.
     /// Otherwise, this method returns `None`.
     pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<BeatmapsetSearchResult>> {
-        let cursor = self.cursor.as_deref()?;
-        let params = &self.params;
+        let cursor = self.cursor.clone()?;
+
+        Some(Self::next_request(osu, cursor, &self.params).await)
+    }
 
+    /// Turn this search result into a [`Stream`](futures::Stream) of all
+    /// matching mapsets, including those on subsequent pages.
+    #[inline]
+    pub fn into_stream(self, osu: &Osu) -> Paginator<'_, Self> {
+        Paginator::new(osu, self)
+    }
+
+    fn next_request<'a>(
+        osu: &'a Osu,
+        cursor: BeatmapsetSearchCursor,
+        params: &BeatmapsetSearchParameters,
+    ) -> GetBeatmapsetSearch<'a> {
         let mut fut = osu
             .beatmapset_search()
             .cursor(cursor)
@@ -1359,7 +1939,26 @@ impl BeatmapsetSearchResult {
             fut = fut.language(language);
         }
 
-        Some(fut.await)
+        fut
+    }
+}
+
+impl Paginated for BeatmapsetSearchResult {
+    type Item = BeatmapsetExtended;
+
+    fn next_page<'a>(&self, osu: &'a Osu) -> Option<PageFuture<'a, Self>> {
+        let cursor = self.cursor.clone()?;
+        let req = Self::next_request(osu, cursor, &self.params);
+
+        Some(Box::pin(async move { req.await }))
+    }
+
+    fn items(&self) -> &[Self::Item] {
+        &self.mapsets
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.mapsets
     }
 }
 
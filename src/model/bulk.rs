@@ -0,0 +1,13 @@
+/// Items requested by id in bulk.
+///
+/// Returned by [`Osu::users_bulk`](crate::Osu::users_bulk) and
+/// [`Osu::beatmaps_bulk`](crate::Osu::beatmaps_bulk).
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct BulkLookup<T> {
+    /// The found items in the order of the requested ids, without duplicates
+    pub items: Vec<T>,
+    /// The requested ids for which the osu!api provided no item, without
+    /// duplicates
+    pub missing: Vec<u32>,
+}
@@ -0,0 +1,156 @@
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+use super::{serde_util, user::User, CacheUserFn, ContainedUsers};
+
+/// The type of a [`ChatChannel`]
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ChatChannelType {
+    Public,
+    Private,
+    Multiplayer,
+    Spectator,
+    Temporary,
+    Pm,
+    Group,
+    Announce,
+    Team,
+}
+
+/// A chat channel
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct ChatChannel {
+    pub channel_id: u64,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    #[serde(rename = "type")]
+    pub kind: ChatChannelType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_length_limit: Option<u32>,
+    pub moderated: bool,
+    /// Value from requests that is relayed back to the sender
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    /// Only available for channels of the current user
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_user_attributes: Option<ChatChannelUserAttributes>,
+    /// Only available for channels of the current user
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_message_id: Option<u64>,
+    /// User ids of the channel's members; only available for private
+    /// messages, groups, and announcements
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<u32>>,
+}
+
+impl ContainedUsers for ChatChannel {
+    fn apply_to_users(&self, _: impl CacheUserFn) {}
+}
+
+/// Attributes of a [`ChatChannel`] regarding the current user
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct ChatChannelUserAttributes {
+    pub can_message: bool,
+    /// Why the current user can't send messages
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub can_message_error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_read_id: Option<u64>,
+}
+
+/// A [`ChatChannel`] including its members
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct ChatChannelDetails {
+    pub channel: ChatChannel,
+    pub users: Vec<User>,
+}
+
+impl ContainedUsers for ChatChannelDetails {
+    fn apply_to_users(&self, f: impl CacheUserFn) {
+        self.users.apply_to_users(f);
+    }
+}
+
+/// The type of a [`ChatMessage`]
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[serde(rename_all = "lowercase")]
+pub enum ChatMessageType {
+    Action,
+    Markdown,
+    Plain,
+}
+
+/// A message sent in a [`ChatChannel`]
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct ChatMessage {
+    pub channel_id: u64,
+    pub content: String,
+    pub is_action: bool,
+    pub message_id: u64,
+    pub sender_id: u32,
+    #[serde(with = "serde_util::datetime")]
+    pub timestamp: OffsetDateTime,
+    #[serde(rename = "type")]
+    pub kind: ChatMessageType,
+    /// Value from requests that is relayed back to the sender
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<User>,
+}
+
+impl ContainedUsers for ChatMessage {
+    fn apply_to_users(&self, f: impl CacheUserFn) {
+        self.sender.apply_to_users(f);
+    }
+}
+
+/// The response of sending a private message through
+/// [`Osu::send_private_message`](crate::Osu::send_private_message)
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct ChatPrivateMessage {
+    /// The channel of the conversation
+    pub channel: ChatChannel,
+    /// The sent message
+    pub message: ChatMessage,
+}
+
+impl ContainedUsers for ChatPrivateMessage {
+    fn apply_to_users(&self, f: impl CacheUserFn) {
+        self.message.apply_to_users(f);
+    }
+}
+
+/// The response of acknowledging the chat through
+/// [`Osu::chat_ack`](crate::Osu::chat_ack)
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct ChatAck {
+    /// Silences that were issued since the requested silence id
+    pub silences: Vec<UserSilence>,
+}
+
+impl ContainedUsers for ChatAck {
+    fn apply_to_users(&self, _: impl CacheUserFn) {}
+}
+
+/// A silenced user whose messages should be hidden
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct UserSilence {
+    /// The id of the silence
+    pub id: u64,
+    /// The id of the silenced user
+    pub user_id: u32,
+}
@@ -3,9 +3,13 @@ use std::fmt;
 use serde::{Deserialize, Serializer};
 use time::OffsetDateTime;
 
-use crate::{prelude::Username, request::GetUser, Osu, OsuResult};
+use crate::{
+    prelude::Username,
+    request::{GetUser, PageFuture, Paginated, Paginator},
+    Osu, OsuResult,
+};
 
-use super::{serde_util, user::User, CacheUserFn, ContainedUsers};
+use super::{cursor::CommentsCursor, serde_util, user::User, CacheUserFn, ContainedUsers};
 
 /// Represents an single comment.
 #[derive(Clone, Debug, Deserialize)]
@@ -95,7 +99,7 @@ pub struct CommentBundle {
         rename = "cursor_string",
         skip_serializing_if = "Option::is_none"
     )]
-    pub(crate) cursor: Option<Box<str>>,
+    pub(crate) cursor: Option<CommentsCursor>,
     /// If there are more comments or replies available
     pub(crate) has_more: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -129,6 +133,15 @@ impl CommentBundle {
         self.has_more
     }
 
+    /// The cursor to request the next page of comments, if available.
+    ///
+    /// Can be stored and passed to
+    /// [`GetComments::cursor`](crate::request::GetComments::cursor) later on.
+    #[inline]
+    pub const fn cursor(&self) -> Option<&CommentsCursor> {
+        self.cursor.as_ref()
+    }
+
     /// If [`has_more`](CommentBundle::has_more) is true, the API can provide
     /// the next set of comments and this method will request them. Otherwise,
     /// this method returns `None`.
@@ -136,7 +149,32 @@ impl CommentBundle {
     pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<CommentBundle>> {
         debug_assert!(self.has_more == self.cursor.is_some());
 
-        Some(osu.comments().cursor(self.cursor.as_deref()?).await)
+        Some(osu.comments().cursor(self.cursor.clone()?).await)
+    }
+
+    /// Turn this bundle into a [`Stream`](futures::Stream) of all top-level comments,
+    /// including those on subsequent pages.
+    #[inline]
+    pub fn into_stream(self, osu: &Osu) -> Paginator<'_, Self> {
+        Paginator::new(osu, self)
+    }
+}
+
+impl Paginated for CommentBundle {
+    type Item = Comment;
+
+    fn next_page<'a>(&self, osu: &'a Osu) -> Option<PageFuture<'a, Self>> {
+        let cursor = self.cursor.clone()?;
+
+        Some(Box::pin(async move { osu.comments().cursor(cursor).await }))
+    }
+
+    fn items(&self) -> &[Self::Item] {
+        &self.comments
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.comments
     }
 }
 
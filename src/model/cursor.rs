@@ -0,0 +1,127 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use serde::{Deserialize, Serialize};
+
+macro_rules! define_cursor {
+    ( $( $( #[doc = $doc:literal] )* $name:ident, )* ) => {
+        $(
+            $( #[doc = $doc] )*
+            ///
+            /// The cursor is opaque; it can be (de)serialized to store it and
+            /// resume fetching pages later on but its content should not be
+            /// relied upon.
+            #[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+            #[serde(transparent)]
+            pub struct $name(Box<str>);
+
+            impl $name {
+                /// Create a cursor from a string previously obtained through
+                #[doc = concat!("[`", stringify!($name), "::as_str`].")]
+                pub fn new(cursor: impl Into<Box<str>>) -> Self {
+                    Self(cursor.into())
+                }
+
+                /// Return the cursor's string representation.
+                pub const fn as_str(&self) -> &str {
+                    &self.0
+                }
+            }
+
+            impl Display for $name {
+                fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                    f.write_str(&self.0)
+                }
+            }
+        )*
+    };
+}
+
+define_cursor! {
+    /// Cursor to resume [`GetBeatmapPacks`](crate::request::GetBeatmapPacks)
+    /// requests.
+    ///
+    /// The cursor is only valid for the same pack type.
+    BeatmapPacksCursor,
+    /// Cursor to resume
+    /// [`GetBeatmapsetDiscussionPosts`](crate::request::GetBeatmapsetDiscussionPosts)
+    /// requests.
+    ///
+    /// The cursor is only valid for the same filters.
+    BeatmapsetDiscussionPostsCursor,
+    /// Cursor to resume
+    /// [`GetBeatmapsetDiscussions`](crate::request::GetBeatmapsetDiscussions)
+    /// requests.
+    ///
+    /// The cursor is only valid for the same filters.
+    BeatmapsetDiscussionsCursor,
+    /// Cursor to resume
+    /// [`GetBeatmapsetDiscussionVotes`](crate::request::GetBeatmapsetDiscussionVotes)
+    /// requests.
+    ///
+    /// The cursor is only valid for the same filters.
+    BeatmapsetDiscussionVotesCursor,
+    /// Cursor to resume [`GetBeatmapsetSearch`](crate::request::GetBeatmapsetSearch)
+    /// requests.
+    ///
+    /// The cursor is only valid for the same search parameters.
+    BeatmapsetSearchCursor,
+    /// Cursor to resume [`GetComments`](crate::request::GetComments) requests.
+    CommentsCursor,
+    /// Cursor to resume [`GetEvents`](crate::request::GetEvents) requests.
+    ///
+    /// The cursor is only valid for the same sort order.
+    EventsCursor,
+    /// Cursor to resume [`GetForumPosts`](crate::request::GetForumPosts)
+    /// requests.
+    ///
+    /// The cursor is only valid for the same topic and sort order.
+    ForumPostsCursor,
+    /// Cursor to resume [`GetForumTopics`](crate::request::GetForumTopics)
+    /// requests.
+    ///
+    /// The cursor is only valid for the same forum and sort order.
+    ForumTopicsCursor,
+    /// Cursor to resume [`GetMatches`](crate::request::GetMatches) requests.
+    MatchesCursor,
+    /// Cursor to resume [`GetNews`](crate::request::GetNews) requests.
+    NewsCursor,
+    /// Cursor to resume
+    /// [`GetPlaylistScores`](crate::request::GetPlaylistScores) requests.
+    ///
+    /// The cursor is only valid for the same playlist item and sort order.
+    PlaylistScoresCursor,
+    /// Cursor to resume [`GetScores`](crate::request::GetScores) requests.
+    ScoresCursor,
+}
+
+/// Checkpoint of a [`ScoreFeed`](crate::request::ScoreFeed).
+///
+/// Store the checkpoint of each batch once its scores were processed and pass
+/// it to [`ScoreFeed::resume`](crate::request::ScoreFeed::resume) to continue
+/// without gaps or duplicates.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct ScoreFeedCheckpoint {
+    cursor: ScoresCursor,
+    last_score_id: u64,
+}
+
+impl ScoreFeedCheckpoint {
+    /// Create a checkpoint from a cursor and the id of the last processed
+    /// score.
+    pub const fn new(cursor: ScoresCursor, last_score_id: u64) -> Self {
+        Self {
+            cursor,
+            last_score_id,
+        }
+    }
+
+    /// The cursor to request the next batch of scores.
+    pub const fn cursor(&self) -> &ScoresCursor {
+        &self.cursor
+    }
+
+    /// The id of the last score that was yielded.
+    pub const fn last_score_id(&self) -> u64 {
+        self.last_score_id
+    }
+}
@@ -1,15 +1,19 @@
-use std::fmt;
+use std::{collections::HashMap, fmt};
 
 use serde::{de, Deserialize};
 use time::OffsetDateTime;
 
-use crate::{Osu, OsuResult};
+use crate::{
+    request::{PageFuture, Paginated, Paginator},
+    Osu, OsuResult,
+};
 
 use super::{
     beatmap::RankStatus,
+    cursor::EventsCursor,
     serde_util,
     user::{Medal, Username},
-    CacheUserFn, ContainedUsers, GameMode, Grade,
+    CacheUserFn, ContainedUsers, GameMode, Grade, StaleNameFn,
 };
 
 #[derive(Clone, Debug, Deserialize)]
@@ -17,7 +21,7 @@ use super::{
 pub struct Events {
     pub events: Vec<Event>,
     #[serde(rename = "cursor_string", skip_serializing_if = "Option::is_none")]
-    pub cursor: Option<String>,
+    pub cursor: Option<EventsCursor>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) sort: Option<EventSort>,
 }
@@ -34,7 +38,7 @@ impl Events {
     /// set of events and this method will request them. Otherwise, this method
     /// returns `None`.
     pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<Events>> {
-        let cursor = self.cursor.as_deref()?;
+        let cursor = self.cursor.clone()?;
 
         let fut = osu
             .events()
@@ -43,11 +47,69 @@ impl Events {
 
         Some(fut.await)
     }
+
+    /// Turn these events into a [`Stream`](futures::Stream) of all events,
+    /// including those on subsequent pages.
+    #[inline]
+    pub fn into_stream(self, osu: &Osu) -> Paginator<'_, Self> {
+        Paginator::new(osu, self)
+    }
+}
+
+impl Paginated for Events {
+    type Item = Event;
+
+    fn next_page<'a>(&self, osu: &'a Osu) -> Option<PageFuture<'a, Self>> {
+        let cursor = self.cursor.clone()?;
+        let sort = self.sort.unwrap_or_default();
+
+        Some(Box::pin(async move {
+            osu.events().cursor(cursor).sort(sort).await
+        }))
+    }
+
+    fn items(&self) -> &[Self::Item] {
+        &self.events
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.events
+    }
 }
 
 impl ContainedUsers for Events {
     fn apply_to_users(&self, f: impl CacheUserFn) {
-        self.events.apply_to_users(f);
+        // Events may be sorted either way so only the newest rename of each
+        // user determines their current name
+        let mut newest = HashMap::new();
+
+        for event in self.events.iter() {
+            let EventType::UsernameChange { ref user } = event.event_type else {
+                continue;
+            };
+
+            let Some(user_id) = user.user_id() else {
+                continue;
+            };
+
+            newest
+                .entry(user_id)
+                .and_modify(|(event_id, username)| {
+                    if *event_id < event.event_id {
+                        *event_id = event.event_id;
+                        *username = &user.username;
+                    }
+                })
+                .or_insert((event.event_id, &user.username));
+        }
+
+        for (user_id, (_, username)) in newest {
+            f(user_id, username);
+        }
+    }
+
+    fn apply_to_stale_names(&self, f: impl StaleNameFn) {
+        self.events.apply_to_stale_names(f);
     }
 }
 
@@ -64,7 +126,17 @@ pub struct Event {
 }
 
 impl ContainedUsers for Event {
+    // A single event can't tell whether its rename is the newest one of the
+    // user so new names are only cached through `Events`
     fn apply_to_users(&self, _: impl CacheUserFn) {}
+
+    fn apply_to_stale_names(&self, f: impl StaleNameFn) {
+        if let EventType::UsernameChange { ref user } = self.event_type {
+            if let (Some(user_id), Some(previous)) = (user.user_id(), &user.previous_username) {
+                f(user_id, previous);
+            }
+        }
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -214,3 +286,15 @@ pub struct EventUser {
     )]
     pub previous_username: Option<Username>,
 }
+
+impl EventUser {
+    /// The user id as contained in the url, e.g. `/u/2`.
+    pub fn user_id(&self) -> Option<u32> {
+        self.url
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()?
+            .parse()
+            .ok()
+    }
+}
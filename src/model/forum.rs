@@ -6,7 +6,44 @@ use serde::{
 };
 use time::OffsetDateTime;
 
-use super::{serde_util, CacheUserFn, ContainedUsers};
+use crate::{
+    request::{GetForumPosts, GetForumTopics, PageFuture, Paginated, Paginator},
+    Osu, OsuResult,
+};
+
+use super::{
+    cursor::{ForumPostsCursor, ForumTopicsCursor},
+    serde_util, CacheUserFn, ContainedUsers,
+};
+
+/// A forum, e.g. "Help" or "Tournaments"
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct Forum {
+    #[serde(rename = "id")]
+    pub forum_id: u32,
+    pub name: String,
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subforums: Vec<Forum>,
+}
+
+impl ContainedUsers for Forum {
+    fn apply_to_users(&self, _: impl CacheUserFn) {}
+}
+
+/// A [`Forum`] including its topics
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct ForumDetails {
+    pub forum: Forum,
+    pub topics: Vec<ForumTopic>,
+    pub pinned_topics: Vec<ForumTopic>,
+}
+
+impl ContainedUsers for ForumDetails {
+    fn apply_to_users(&self, _: impl CacheUserFn) {}
+}
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -16,7 +53,7 @@ pub struct ForumPosts {
         rename = "cursor_string",
         skip_serializing_if = "Option::is_none"
     )]
-    pub cursor: Option<String>,
+    pub cursor: Option<ForumPostsCursor>,
     pub posts: Vec<ForumPost>,
     pub search: ForumPostsSearch,
     pub topic: ForumTopic,
@@ -26,19 +63,89 @@ impl ForumPosts {
     /// Checks whether the cursor field is `Some` which in turn
     /// can be used to retrieve the next set of posts.
     ///
-    /// The next set can then be retrieved by providing this cursor to
+    /// The next set can then be retrieved through
+    /// [`get_next`](ForumPosts::get_next) or by providing this cursor to
     /// [`GetForumPosts::cursor`](crate::request::GetForumPosts::cursor).
     /// Be sure all other parameters stay the same.
     #[inline]
     pub const fn has_more(&self) -> bool {
         self.cursor.is_some()
     }
+
+    /// If [`has_more`](ForumPosts::has_more) is true, the API can provide the
+    /// next set of posts and this method will request them. Otherwise, this
+    /// method returns `None`.
+    ///
+    /// The sort order and limit are the same as for the current posts.
+    pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<ForumPosts>> {
+        Some(self.next_request(osu)?.await)
+    }
+
+    /// Turn these posts into a [`Stream`](futures::Stream) of all posts of
+    /// the topic, including those on subsequent pages.
+    #[inline]
+    pub fn into_stream(self, osu: &Osu) -> Paginator<'_, Self> {
+        Paginator::new(osu, self)
+    }
+
+    fn next_request<'a>(&self, osu: &'a Osu) -> Option<GetForumPosts<'a>> {
+        let cursor = self.cursor.clone()?;
+        let last_id = self.posts.last()?.post_id;
+
+        let req = osu
+            .forum_posts(self.topic.topic_id)
+            .limit(self.search.limit as usize)
+            .cursor(cursor);
+
+        // The cursor takes precedence but the bounds are still kept in line
+        // with it in case the osu!api ignores it.
+        let req = if self.search.sort == "id_desc" {
+            req.sort_descending().end_id(last_id.saturating_sub(1))
+        } else {
+            req.sort_ascending().start_id(last_id + 1)
+        };
+
+        Some(req)
+    }
+}
+
+impl Paginated for ForumPosts {
+    type Item = ForumPost;
+
+    fn next_page<'a>(&self, osu: &'a Osu) -> Option<PageFuture<'a, Self>> {
+        let req = self.next_request(osu)?;
+
+        Some(Box::pin(async move { req.await }))
+    }
+
+    fn items(&self) -> &[Self::Item] {
+        &self.posts
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.posts
+    }
 }
 
 impl ContainedUsers for ForumPosts {
     fn apply_to_users(&self, _: impl CacheUserFn) {}
 }
 
+/// The response of creating a topic through
+/// [`Osu::create_forum_topic`](crate::Osu::create_forum_topic)
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct CreatedForumTopic {
+    /// The created topic
+    pub topic: ForumTopic,
+    /// The first post of the topic
+    pub post: ForumPost,
+}
+
+impl ContainedUsers for CreatedForumTopic {
+    fn apply_to_users(&self, _: impl CacheUserFn) {}
+}
+
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct ForumPost {
@@ -175,6 +282,10 @@ impl PartialEq for ForumPost {
 
 impl Eq for ForumPost {}
 
+impl ContainedUsers for ForumPost {
+    fn apply_to_users(&self, _: impl CacheUserFn) {}
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct ForumPostsSearch {
@@ -220,3 +331,174 @@ impl PartialEq for ForumTopic {
 }
 
 impl Eq for ForumTopic {}
+
+impl ContainedUsers for ForumTopic {
+    fn apply_to_users(&self, _: impl CacheUserFn) {}
+}
+
+/// A poll to attach to a topic created through
+/// [`CreateForumTopic::poll`](crate::request::CreateForumTopic::poll)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForumTopicPoll {
+    pub title: String,
+    pub options: Vec<String>,
+    /// How many options each user may vote for
+    pub max_options: u32,
+    /// How many days the poll stays open, `0` for no limit
+    pub length_days: u32,
+    /// Whether the results are hidden until the poll ends
+    pub hide_results: bool,
+    /// Whether users may change their vote
+    pub vote_change: bool,
+}
+
+impl ForumTopicPoll {
+    /// Create a new poll that allows one vote per user, stays open
+    /// indefinitely, shows its results, and does not allow changing votes.
+    pub fn new<I, S>(title: impl Into<String>, options: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            title: title.into(),
+            options: options.into_iter().map(Into::into).collect(),
+            max_options: 1,
+            length_days: 0,
+            hide_results: false,
+            vote_change: false,
+        }
+    }
+}
+
+/// How to sort topics requested through
+/// [`Osu::forum_topics`](crate::Osu::forum_topics)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ForumTopicSort {
+    /// Most recently active topics first
+    #[default]
+    New,
+    /// Least recently active topics first
+    Old,
+}
+
+impl ForumTopicSort {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::New => "new",
+            Self::Old => "old",
+        }
+    }
+}
+
+impl serde::Serialize for ForumTopicSort {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+/// A page of [`ForumTopic`]s.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct ForumTopics {
+    pub topics: Vec<ForumTopic>,
+    #[serde(
+        default,
+        rename = "cursor_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub(crate) cursor: Option<ForumTopicsCursor>,
+    #[serde(skip)]
+    pub(crate) request: Option<ForumTopicsRequest>,
+}
+
+/// Parameters of the request that provided [`ForumTopics`] to request the
+/// next page with.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct ForumTopicsRequest {
+    pub(crate) forum_id: Option<u32>,
+    pub(crate) sort: Option<ForumTopicSort>,
+    pub(crate) limit: Option<usize>,
+}
+
+impl ForumTopics {
+    /// Returns whether there is a next page of topics, retrievable via
+    /// [`get_next`](ForumTopics::get_next).
+    #[inline]
+    pub const fn has_more(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    /// The cursor to request the next page of topics, if available.
+    ///
+    /// Can be stored and passed to
+    /// [`GetForumTopics::cursor`](crate::request::GetForumTopics::cursor)
+    /// later on.
+    #[inline]
+    pub const fn cursor(&self) -> Option<&ForumTopicsCursor> {
+        self.cursor.as_ref()
+    }
+
+    /// If [`has_more`](ForumTopics::has_more) is true, the API can provide the
+    /// next set of topics and this method will request them. Otherwise, this
+    /// method returns `None`.
+    ///
+    /// The forum, sort order, and limit are the same as for the current
+    /// topics.
+    #[inline]
+    pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<ForumTopics>> {
+        Some(self.next_request(osu)?.await)
+    }
+
+    /// Turn these topics into a [`Stream`](futures::Stream) of all topics,
+    /// including those on subsequent pages.
+    #[inline]
+    pub fn into_stream(self, osu: &Osu) -> Paginator<'_, Self> {
+        Paginator::new(osu, self)
+    }
+
+    fn next_request<'a>(&self, osu: &'a Osu) -> Option<GetForumTopics<'a>> {
+        let mut req = osu.forum_topics().cursor(self.cursor.clone()?);
+        let ForumTopicsRequest {
+            forum_id,
+            sort,
+            limit,
+        } = self.request.unwrap_or_default();
+
+        if let Some(forum_id) = forum_id {
+            req = req.forum_id(forum_id);
+        }
+
+        if let Some(sort) = sort {
+            req = req.sort(sort);
+        }
+
+        if let Some(limit) = limit {
+            req = req.limit(limit);
+        }
+
+        Some(req)
+    }
+}
+
+impl Paginated for ForumTopics {
+    type Item = ForumTopic;
+
+    fn next_page<'a>(&self, osu: &'a Osu) -> Option<PageFuture<'a, Self>> {
+        let req = self.next_request(osu)?;
+
+        Some(Box::pin(async move { req.await }))
+    }
+
+    fn items(&self) -> &[Self::Item] {
+        &self.topics
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.topics
+    }
+}
+
+impl ContainedUsers for ForumTopics {
+    fn apply_to_users(&self, _: impl CacheUserFn) {}
+}
@@ -1,4 +1,9 @@
-use std::{collections::HashMap, fmt, slice::Iter, vec::Drain};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt, mem,
+    slice::Iter,
+    vec::Drain,
+};
 
 use rosu_mods::serde::GameModsSeed;
 use serde::{
@@ -10,10 +15,15 @@ use serde::{
 use serde_json::value::RawValue;
 use time::OffsetDateTime;
 
-use crate::{error::OsuError, Osu, OsuResult};
+use crate::{
+    error::OsuError,
+    request::{MatchWatcher, PageFuture, Paginated, Paginator},
+    Osu, OsuResult,
+};
 
 use super::{
     beatmap::Beatmap,
+    cursor::MatchesCursor,
     mods::{GameMods, GameModsIntermode},
     score::LegacyScoreStatistics,
     serde_util,
@@ -297,6 +307,25 @@ impl<'de> Deserialize<'de> for MatchEvent {
     }
 }
 
+/// A change of a match as yielded by a [`MatchWatcher`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum MatchUpdate {
+    /// A player joined the match
+    Joined { user_id: u32 },
+    /// A player left the match
+    Left { user_id: u32 },
+    /// A player was kicked from the match
+    Kicked { user_id: u32 },
+    /// The match host changed
+    HostChanged { user_id: u32 },
+    /// A map started being played
+    GameStarted { game: Box<MatchGame> },
+    /// A map was played to the end; contains the final scores
+    GameFinished { game: Box<MatchGame> },
+    /// The match was closed
+    Disbanded,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct MatchGame {
@@ -496,7 +525,7 @@ pub struct MatchList {
         rename = "cursor_string",
         skip_serializing_if = "Option::is_none"
     )]
-    pub(crate) cursor: Option<Box<str>>,
+    pub(crate) cursor: Option<MatchesCursor>,
     pub matches: Vec<MatchInfo>,
     pub params: MatchListParams,
 }
@@ -509,12 +538,48 @@ impl MatchList {
         self.cursor.is_some()
     }
 
+    /// The cursor to request the next page of matches, if available.
+    ///
+    /// Can be stored and passed to
+    /// [`GetMatches::cursor`](crate::request::GetMatches::cursor) later on.
+    #[inline]
+    pub const fn cursor(&self) -> Option<&MatchesCursor> {
+        self.cursor.as_ref()
+    }
+
     /// If [`has_more`](MatchList::has_more) is true, the API can provide the
     /// next set of matches and this method will request them. Otherwise, this
     /// method returns `None`.
     #[inline]
     pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<MatchList>> {
-        Some(osu.osu_matches().cursor(self.cursor.as_deref()?).await)
+        Some(osu.osu_matches().cursor(self.cursor.clone()?).await)
+    }
+
+    /// Turn this match list into a [`Stream`](futures::Stream) of all matches,
+    /// including those on subsequent pages.
+    #[inline]
+    pub fn into_stream(self, osu: &Osu) -> Paginator<'_, Self> {
+        Paginator::new(osu, self)
+    }
+}
+
+impl Paginated for MatchList {
+    type Item = MatchInfo;
+
+    fn next_page<'a>(&self, osu: &'a Osu) -> Option<PageFuture<'a, Self>> {
+        let cursor = self.cursor.clone()?;
+
+        Some(Box::pin(
+            async move { osu.osu_matches().cursor(cursor).await },
+        ))
+    }
+
+    fn items(&self) -> &[Self::Item] {
+        &self.matches
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.matches
     }
 }
 
@@ -837,19 +902,64 @@ impl OsuMatch {
     /// Convenient to display a "live" update of the match, e.g. the way an mp link
     /// pulls the next result every 10 seconds.
     pub async fn get_next(&self, osu: &Osu) -> OsuResult<OsuMatch> {
-        let mut last_id = self.latest_event_id;
+        osu.osu_match(self.match_id)
+            .after(self.resume_after())
+            .limit(100)
+            .await
+    }
 
+    /// The event id after which the next events should be requested so that
+    /// an in-progress game is included again.
+    pub(crate) fn resume_after(&self) -> u64 {
         for event in self.events.iter().rev() {
             if let MatchEvent::Game { event_id, game, .. } = event {
                 if game.end_time.is_none() {
-                    last_id = event_id - 1;
+                    return event_id - 1;
                 }
 
                 break;
             }
         }
 
-        osu.osu_match(self.match_id).after(last_id).limit(100).await
+        self.latest_event_id
+    }
+
+    /// Merge the events and users of a more recently fetched chunk of the
+    /// same match into this one.
+    ///
+    /// Events are de-duplicated by their id and kept in order; the chunk's
+    /// version of an event replaces the known one. Returns how many events
+    /// were new.
+    pub(crate) fn merge(&mut self, chunk: OsuMatch) -> usize {
+        let mut events: BTreeMap<_, _> = mem::take(&mut self.events)
+            .into_iter()
+            .map(|event| (event.event_id(), event))
+            .collect();
+
+        let known = events.len();
+        events.extend(
+            chunk
+                .events
+                .into_iter()
+                .map(|event| (event.event_id(), event)),
+        );
+        let added = events.len() - known;
+
+        self.events = events.into_values().collect();
+        self.users.extend(chunk.users);
+        self.current_game_id = chunk.current_game_id;
+        self.end_time = chunk.end_time;
+        self.latest_event_id = chunk.latest_event_id;
+        self.name = chunk.name;
+
+        added
+    }
+
+    /// Turn this match into a [`MatchWatcher`] that polls the match for new
+    /// events and yields them as [`MatchUpdate`]s.
+    #[inline]
+    pub fn watch(self, osu: &Osu) -> MatchWatcher<'_> {
+        MatchWatcher::new(osu, self)
     }
 
     /// The API sends only up to 100 events per request.
@@ -132,15 +132,22 @@ macro_rules! def_enum {
     }
 }
 
+mod bulk;
 mod grade;
 mod serde_util;
 
 /// Beatmap(set) related types
 pub mod beatmap;
 
+/// Chat related types
+pub mod chat;
+
 /// Comment related types
 pub mod comments;
 
+/// Cursors to resume paginated requests
+pub mod cursor;
+
 /// Event related types
 pub mod event;
 
@@ -162,6 +169,9 @@ pub mod news;
 /// Ranking related types
 pub mod ranking;
 
+/// Multiplayer room related types
+pub mod room;
+
 /// Score related types
 pub mod score;
 
@@ -178,7 +188,7 @@ use std::{collections::HashMap, marker::PhantomData};
 
 pub use rosu_mods::GameMode;
 
-pub use self::{grade::Grade, serde_util::DeserializedList};
+pub use self::{bulk::BulkLookup, grade::Grade, serde_util::DeserializedList};
 
 use self::user::Username;
 
@@ -197,6 +207,13 @@ pub(crate) trait CacheUserFn: Copy + Fn(u32, &Username) {}
 
 impl<T: Copy + Fn(u32, &Username)> CacheUserFn for T {}
 
+/// Trait alias for `Copy + Fn(u32, &Username)`.
+///
+/// Awaiting <https://github.com/rust-lang/rust/issues/41517>
+pub(crate) trait StaleNameFn: Copy + Fn(u32, &Username) {}
+
+impl<T: Copy + Fn(u32, &Username)> StaleNameFn for T {}
+
 /// A way to apply a given function to all users contained in `Self`.
 pub(crate) trait ContainedUsers {
     /// Applies `f` to all (user id, username) pairs contained in `self`.
@@ -205,12 +222,28 @@ pub(crate) trait ContainedUsers {
         allow(dead_code, reason = "its only used to put all users in the cache")
     )]
     fn apply_to_users(&self, f: impl CacheUserFn);
+
+    /// Applies `f` to all (user id, username) pairs contained in `self` whose
+    /// name no longer belongs to the user, e.g. because the user was renamed.
+    #[cfg_attr(
+        not(feature = "cache"),
+        allow(dead_code, reason = "its only used to evict names from the cache")
+    )]
+    fn apply_to_stale_names(&self, _: impl StaleNameFn) {}
+}
+
+impl ContainedUsers for () {
+    fn apply_to_users(&self, _: impl CacheUserFn) {}
 }
 
 impl<T: ContainedUsers> ContainedUsers for Box<T> {
     fn apply_to_users(&self, f: impl CacheUserFn) {
         (**self).apply_to_users(f);
     }
+
+    fn apply_to_stale_names(&self, f: impl StaleNameFn) {
+        (**self).apply_to_stale_names(f);
+    }
 }
 
 impl<T: ContainedUsers> ContainedUsers for Option<T> {
@@ -219,6 +252,12 @@ impl<T: ContainedUsers> ContainedUsers for Option<T> {
             item.apply_to_users(f);
         }
     }
+
+    fn apply_to_stale_names(&self, f: impl StaleNameFn) {
+        if let Some(item) = self {
+            item.apply_to_stale_names(f);
+        }
+    }
 }
 
 impl<T: ContainedUsers, E> ContainedUsers for Result<T, E> {
@@ -227,6 +266,12 @@ impl<T: ContainedUsers, E> ContainedUsers for Result<T, E> {
             ok.apply_to_users(f);
         }
     }
+
+    fn apply_to_stale_names(&self, f: impl StaleNameFn) {
+        if let Ok(ok) = self {
+            ok.apply_to_stale_names(f);
+        }
+    }
 }
 
 impl<T: ContainedUsers> ContainedUsers for Vec<T> {
@@ -235,6 +280,12 @@ impl<T: ContainedUsers> ContainedUsers for Vec<T> {
             item.apply_to_users(f);
         }
     }
+
+    fn apply_to_stale_names(&self, f: impl StaleNameFn) {
+        for item in self {
+            item.apply_to_stale_names(f);
+        }
+    }
 }
 
 impl<K, T: ContainedUsers, S> ContainedUsers for HashMap<K, T, S> {
@@ -243,4 +294,10 @@ impl<K, T: ContainedUsers, S> ContainedUsers for HashMap<K, T, S> {
             value.apply_to_users(f);
         }
     }
+
+    fn apply_to_stale_names(&self, f: impl StaleNameFn) {
+        for value in self.values() {
+            value.apply_to_stale_names(f);
+        }
+    }
 }
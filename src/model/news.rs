@@ -1,9 +1,13 @@
 use serde::Deserialize;
 use time::OffsetDateTime;
 
-use crate::{prelude::Username, Osu, OsuResult};
+use crate::{
+    prelude::Username,
+    request::{PageFuture, Paginated, Paginator},
+    Osu, OsuResult,
+};
 
-use super::{serde_util, CacheUserFn, ContainedUsers};
+use super::{cursor::NewsCursor, serde_util, CacheUserFn, ContainedUsers};
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -13,7 +17,7 @@ pub struct News {
         rename = "cursor_string",
         skip_serializing_if = "Option::is_none"
     )]
-    pub(crate) cursor: Option<String>,
+    pub(crate) cursor: Option<NewsCursor>,
     #[serde(rename = "news_posts")]
     pub posts: Vec<NewsPost>,
     pub search: NewsSearch,
@@ -29,11 +33,45 @@ impl News {
         self.cursor.is_some()
     }
 
+    /// The cursor to request the next page of news, if available.
+    ///
+    /// Can be stored and passed to
+    /// [`GetNews::cursor`](crate::request::GetNews::cursor) later on.
+    #[inline]
+    pub const fn cursor(&self) -> Option<&NewsCursor> {
+        self.cursor.as_ref()
+    }
+
     /// If [`has_more`](News::has_more) is true, the API can provide the next set of news and this method will request them.
     /// Otherwise, this method returns `None`.
     #[inline]
     pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<News>> {
-        Some(osu.news().cursor(self.cursor.as_deref()?).await)
+        Some(osu.news().cursor(self.cursor.clone()?).await)
+    }
+
+    /// Turn these news into a [`Stream`](futures::Stream) of all news posts,
+    /// including those on subsequent pages.
+    #[inline]
+    pub fn into_stream(self, osu: &Osu) -> Paginator<'_, Self> {
+        Paginator::new(osu, self)
+    }
+}
+
+impl Paginated for News {
+    type Item = NewsPost;
+
+    fn next_page<'a>(&self, osu: &'a Osu) -> Option<PageFuture<'a, Self>> {
+        let cursor = self.cursor.clone()?;
+
+        Some(Box::pin(async move { osu.news().cursor(cursor).await }))
+    }
+
+    fn items(&self) -> &[Self::Item] {
+        &self.posts
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.posts
     }
 }
 
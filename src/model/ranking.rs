@@ -6,7 +6,11 @@ use ::serde::{
 };
 use time::OffsetDateTime;
 
-use crate::{model::user::CountryCode, Osu, OsuResult};
+use crate::{
+    model::user::CountryCode,
+    request::{PageFuture, Paginated, Paginator},
+    Osu, OsuResult,
+};
 
 use super::{
     beatmap::BeatmapsetExtended,
@@ -61,6 +65,8 @@ pub struct CountryRanking {
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct CountryRankings {
+    #[serde(default)]
+    pub(crate) mode: Option<GameMode>,
     /// The next page of the ranking
     #[serde(
         default,
@@ -82,6 +88,34 @@ impl CountryRankings {
     pub async fn get_next(&self, osu: &Osu, mode: GameMode) -> Option<OsuResult<CountryRankings>> {
         Some(osu.country_rankings(mode).page(self.next_page?).await)
     }
+
+    /// Turn these rankings into a [`Stream`](futures::Stream) of all
+    /// countries, including those on subsequent pages.
+    #[inline]
+    pub fn into_stream(self, osu: &Osu) -> Paginator<'_, Self> {
+        Paginator::new(osu, self)
+    }
+}
+
+impl Paginated for CountryRankings {
+    type Item = CountryRanking;
+
+    fn next_page<'a>(&self, osu: &'a Osu) -> Option<PageFuture<'a, Self>> {
+        let page = self.next_page?;
+        let mode = self.mode?;
+
+        Some(Box::pin(async move {
+            osu.country_rankings(mode).page(page).await
+        }))
+    }
+
+    fn items(&self) -> &[Self::Item] {
+        &self.ranking
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.ranking
+    }
 }
 
 impl ContainedUsers for CountryRankings {
@@ -108,6 +142,12 @@ pub struct Rankings {
     #[serde(default)]
     pub(crate) ranking_type: Option<RankingType>,
     pub total: u32,
+    /// Country filter of the request, re-applied for subsequent pages
+    #[serde(skip)]
+    pub(crate) country: Option<CountryCode>,
+    /// Variant filter of the request, re-applied for subsequent pages
+    #[serde(skip)]
+    pub(crate) variant: Option<&'static str>,
 }
 
 impl ContainedUsers for Rankings {
@@ -600,13 +640,60 @@ impl Rankings {
         let kind = self.ranking_type?;
 
         let rankings = match kind {
-            RankingType::Performance => osu.performance_rankings(mode).page(page).await,
+            RankingType::Performance => {
+                osu.performance_rankings(mode)
+                    .filters(self.country.clone(), self.variant)
+                    .page(page)
+                    .await
+            }
             RankingType::Score => osu.score_rankings(mode).page(page).await,
             RankingType::Charts | RankingType::Country | RankingType::Team => unreachable!(),
         };
 
         Some(rankings)
     }
+
+    /// Turn these rankings into a [`Stream`](futures::Stream) of all users,
+    /// including those on subsequent pages.
+    #[inline]
+    pub fn into_stream(self, osu: &Osu) -> Paginator<'_, Self> {
+        Paginator::new(osu, self)
+    }
+}
+
+impl Paginated for Rankings {
+    type Item = User;
+
+    fn next_page<'a>(&self, osu: &'a Osu) -> Option<PageFuture<'a, Self>> {
+        let page = self.next_page?;
+        let mode = self.mode?;
+
+        match self.ranking_type? {
+            RankingType::Performance => {
+                let country = self.country.clone();
+                let variant = self.variant;
+
+                Some(Box::pin(async move {
+                    osu.performance_rankings(mode)
+                        .filters(country, variant)
+                        .page(page)
+                        .await
+                }))
+            }
+            RankingType::Score => Some(Box::pin(async move {
+                osu.score_rankings(mode).page(page).await
+            })),
+            RankingType::Charts | RankingType::Country | RankingType::Team => None,
+        }
+    }
+
+    fn items(&self) -> &[Self::Item] {
+        &self.ranking
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.ranking
+    }
 }
 
 struct RankingsCursorVisitor;
@@ -737,6 +824,34 @@ impl TeamRankings {
 
         Some(osu.team_rankings(mode).page(page).await)
     }
+
+    /// Turn these rankings into a [`Stream`](futures::Stream) of all teams,
+    /// including those on subsequent pages.
+    #[inline]
+    pub fn into_stream(self, osu: &Osu) -> Paginator<'_, Self> {
+        Paginator::new(osu, self)
+    }
+}
+
+impl Paginated for TeamRankings {
+    type Item = TeamRankingsEntry;
+
+    fn next_page<'a>(&self, osu: &'a Osu) -> Option<PageFuture<'a, Self>> {
+        let page = self.next_page?;
+        let mode = self.mode?;
+
+        Some(Box::pin(
+            async move { osu.team_rankings(mode).page(page).await },
+        ))
+    }
+
+    fn items(&self) -> &[Self::Item] {
+        &self.ranking
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.ranking
+    }
 }
 
 impl ContainedUsers for TeamRankings {
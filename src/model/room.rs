@@ -0,0 +1,424 @@
+use rosu_mods::serde::GameModsSeed;
+use serde::{de::DeserializeSeed, Deserialize, Deserializer};
+use serde_json::value::RawValue;
+use time::OffsetDateTime;
+
+use crate::{
+    error::OsuError,
+    request::{GetPlaylistScores, PageFuture, Paginated, Paginator},
+    Osu, OsuResult,
+};
+
+use super::{
+    beatmap::Beatmap, cursor::PlaylistScoresCursor, mods::GameMods, score::Score, serde_util,
+    user::User, CacheUserFn, ContainedUsers, GameMode,
+};
+
+/// A multiplayer room, e.g. a realtime lobby, a playlist, or a daily
+/// challenge.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct Room {
+    #[serde(rename = "id")]
+    pub room_id: u64,
+    pub name: String,
+    pub category: RoomCategory,
+    #[serde(rename = "type")]
+    pub kind: RoomType,
+    /// User id of the room's host
+    pub user_id: u32,
+    #[serde(with = "serde_util::datetime")]
+    pub starts_at: OffsetDateTime,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "serde_util::option_datetime"
+    )]
+    pub ends_at: Option<OffsetDateTime>,
+    /// How often each user may play each playlist item
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_attempts: Option<u32>,
+    pub participant_count: u32,
+    pub channel_id: u64,
+    pub active: bool,
+    pub has_password: bool,
+    pub queue_mode: RoomQueueMode,
+    pub auto_skip: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_playlist_item: Option<PlaylistItem>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<User>,
+    /// Only available when requesting a single room
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub playlist: Vec<PlaylistItem>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_participants: Vec<User>,
+}
+
+impl ContainedUsers for Room {
+    fn apply_to_users(&self, f: impl CacheUserFn) {
+        self.host.apply_to_users(f);
+        self.recent_participants.apply_to_users(f);
+    }
+}
+
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[serde(rename_all = "snake_case")]
+pub enum RoomCategory {
+    Normal,
+    Spotlight,
+    FeaturedArtist,
+    DailyChallenge,
+}
+
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[serde(rename_all = "snake_case")]
+pub enum RoomType {
+    Playlists,
+    HeadToHead,
+    TeamVersus,
+}
+
+/// Who may add items to a room's playlist
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[serde(rename_all = "snake_case")]
+pub enum RoomQueueMode {
+    HostOnly,
+    AllPlayers,
+    AllPlayersRoundRobin,
+}
+
+/// Which rooms to request through [`Osu::rooms`]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoomFilter {
+    #[default]
+    Active,
+    All,
+    Ended,
+    /// Rooms the current user participated in
+    Participated,
+    /// Rooms of the current user
+    Owned,
+}
+
+impl RoomFilter {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::All => "all",
+            Self::Ended => "ended",
+            Self::Participated => "participated",
+            Self::Owned => "owned",
+        }
+    }
+}
+
+impl serde::Serialize for RoomFilter {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+/// How to sort rooms requested through [`Osu::rooms`]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoomSort {
+    #[default]
+    Created,
+    Ended,
+}
+
+impl RoomSort {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Created => "created",
+            Self::Ended => "ended",
+        }
+    }
+}
+
+impl serde::Serialize for RoomSort {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+/// Whether to request playlists or realtime rooms through [`Osu::rooms`]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoomTypeGroup {
+    #[default]
+    Playlists,
+    Realtime,
+}
+
+impl RoomTypeGroup {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Playlists => "playlists",
+            Self::Realtime => "realtime",
+        }
+    }
+}
+
+impl serde::Serialize for RoomTypeGroup {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+/// A map of a [`Room`]'s playlist
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct PlaylistItem {
+    #[cfg_attr(feature = "serialize", serde(rename = "id"))]
+    pub playlist_item_id: u64,
+    pub room_id: u64,
+    #[cfg_attr(feature = "serialize", serde(rename = "beatmap_id"))]
+    pub map_id: u32,
+    #[cfg_attr(feature = "serialize", serde(rename = "ruleset_id"))]
+    pub mode: GameMode,
+    pub allowed_mods: GameMods,
+    pub required_mods: GameMods,
+    pub expired: bool,
+    pub owner_id: u32,
+    #[cfg_attr(feature = "serialize", serde(skip_serializing_if = "Option::is_none"))]
+    pub playlist_order: Option<u32>,
+    #[cfg_attr(
+        feature = "serialize",
+        serde(
+            skip_serializing_if = "Option::is_none",
+            with = "serde_util::option_datetime"
+        )
+    )]
+    pub played_at: Option<OffsetDateTime>,
+    #[cfg_attr(
+        feature = "serialize",
+        serde(rename = "beatmap", skip_serializing_if = "Option::is_none")
+    )]
+    pub map: Option<Box<Beatmap>>,
+}
+
+impl<'de> Deserialize<'de> for PlaylistItem {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct PlaylistItemRawMods {
+            #[serde(rename = "id")]
+            playlist_item_id: u64,
+            room_id: u64,
+            #[serde(rename = "beatmap_id")]
+            map_id: u32,
+            #[serde(rename = "ruleset_id")]
+            mode: GameMode,
+            allowed_mods: Box<RawValue>,
+            required_mods: Box<RawValue>,
+            expired: bool,
+            owner_id: u32,
+            playlist_order: Option<u32>,
+            #[serde(default, with = "serde_util::option_datetime")]
+            played_at: Option<OffsetDateTime>,
+            #[serde(default, rename = "beatmap")]
+            map: Option<Box<Beatmap>>,
+        }
+
+        let item_raw = <PlaylistItemRawMods as serde::Deserialize>::deserialize(d)?;
+
+        let seed = || GameModsSeed::Mode {
+            mode: item_raw.mode,
+            deny_unknown_fields: false,
+        };
+
+        Ok(PlaylistItem {
+            allowed_mods: seed()
+                .deserialize(&*item_raw.allowed_mods)
+                .map_err(|e| OsuError::invalid_mods(&item_raw.allowed_mods, &e))?,
+            required_mods: seed()
+                .deserialize(&*item_raw.required_mods)
+                .map_err(|e| OsuError::invalid_mods(&item_raw.required_mods, &e))?,
+            playlist_item_id: item_raw.playlist_item_id,
+            room_id: item_raw.room_id,
+            map_id: item_raw.map_id,
+            mode: item_raw.mode,
+            expired: item_raw.expired,
+            owner_id: item_raw.owner_id,
+            playlist_order: item_raw.playlist_order,
+            played_at: item_raw.played_at,
+            map: item_raw.map,
+        })
+    }
+}
+
+impl ContainedUsers for PlaylistItem {
+    fn apply_to_users(&self, _: impl CacheUserFn) {}
+}
+
+/// The leaderboard of a [`Room`] across all of its playlist items.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct RoomLeaderboard {
+    pub leaderboard: Vec<UserScoreAggregate>,
+    /// The entry of the current user, if available
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_score: Option<UserScoreAggregate>,
+}
+
+impl ContainedUsers for RoomLeaderboard {
+    fn apply_to_users(&self, f: impl CacheUserFn) {
+        self.leaderboard.apply_to_users(f);
+        self.user_score.apply_to_users(f);
+    }
+}
+
+/// The accumulated scores of a user within a [`Room`].
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct UserScoreAggregate {
+    #[serde(with = "serde_util::adjust_acc")]
+    pub accuracy: f32,
+    pub attempts: u32,
+    /// Amount of completed playlist items
+    pub completed: u32,
+    pub pp: f32,
+    pub room_id: u64,
+    pub total_score: u64,
+    pub user_id: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
+
+impl ContainedUsers for UserScoreAggregate {
+    fn apply_to_users(&self, f: impl CacheUserFn) {
+        self.user.apply_to_users(f);
+    }
+}
+
+/// How to sort scores requested through [`Osu::playlist_scores`]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PlaylistScoresSort {
+    ScoreAscending,
+    #[default]
+    ScoreDescending,
+}
+
+impl PlaylistScoresSort {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ScoreAscending => "score_asc",
+            Self::ScoreDescending => "score_desc",
+        }
+    }
+}
+
+impl serde::Serialize for PlaylistScoresSort {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+/// Scores of a [`PlaylistItem`].
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct PlaylistScores {
+    pub scores: Vec<Score>,
+    /// Total amount of scores on the playlist item
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<u32>,
+    /// The score of the current user, if available
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_score: Option<Score>,
+    #[serde(rename = "cursor_string", skip_serializing_if = "Option::is_none")]
+    pub(crate) cursor: Option<PlaylistScoresCursor>,
+    #[serde(skip)]
+    pub(crate) request: Option<PlaylistScoresRequest>,
+}
+
+/// Parameters of the request that provided [`PlaylistScores`] to request the
+/// next page with.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct PlaylistScoresRequest {
+    pub(crate) room_id: u64,
+    pub(crate) playlist_item_id: u64,
+    pub(crate) limit: Option<usize>,
+    pub(crate) sort: Option<PlaylistScoresSort>,
+}
+
+impl PlaylistScores {
+    /// The cursor to request the next page of scores.
+    ///
+    /// Can be stored and passed to
+    /// [`GetPlaylistScores::cursor`](crate::request::GetPlaylistScores::cursor)
+    /// later on.
+    #[inline]
+    pub const fn cursor(&self) -> Option<&PlaylistScoresCursor> {
+        self.cursor.as_ref()
+    }
+
+    /// Returns whether there is a next page of scores, retrievable via
+    /// [`get_next`](PlaylistScores::get_next).
+    #[inline]
+    pub const fn has_more(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    /// If [`has_more`](PlaylistScores::has_more) is true, the API can provide
+    /// the next page of scores and this method will request them. Otherwise,
+    /// this method returns `None`.
+    #[inline]
+    pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<PlaylistScores>> {
+        Some(self.next_request(osu)?.await)
+    }
+
+    /// Turn these scores into a [`Stream`](futures::Stream) of all scores of
+    /// the playlist item, including those on subsequent pages.
+    #[inline]
+    pub fn into_stream(self, osu: &Osu) -> Paginator<'_, Self> {
+        Paginator::new(osu, self)
+    }
+
+    fn next_request<'a>(&self, osu: &'a Osu) -> Option<GetPlaylistScores<'a>> {
+        let cursor = self.cursor.clone()?;
+        let params = self.request?;
+
+        let mut req = osu
+            .playlist_scores(params.room_id, params.playlist_item_id)
+            .cursor(cursor);
+
+        if let Some(limit) = params.limit {
+            req = req.limit(limit);
+        }
+
+        if let Some(sort) = params.sort {
+            req = req.sort(sort);
+        }
+
+        Some(req)
+    }
+}
+
+impl Paginated for PlaylistScores {
+    type Item = Score;
+
+    fn next_page<'a>(&self, osu: &'a Osu) -> Option<PageFuture<'a, Self>> {
+        let req = self.next_request(osu)?;
+
+        Some(Box::pin(async move { req.await }))
+    }
+
+    fn items(&self) -> &[Self::Item] {
+        &self.scores
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.scores
+    }
+}
+
+impl ContainedUsers for PlaylistScores {
+    fn apply_to_users(&self, f: impl CacheUserFn) {
+        self.scores.apply_to_users(f);
+        self.user_score.apply_to_users(f);
+    }
+}
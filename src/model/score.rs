@@ -6,10 +6,15 @@ use serde::{
 use serde_json::value::RawValue;
 use time::OffsetDateTime;
 
-use crate::{error::OsuError, request::GetUser, Osu, OsuResult};
+use crate::{
+    error::OsuError,
+    request::{GetScores, GetUser, PageFuture, Paginated, Paginator},
+    Osu, OsuResult,
+};
 
 use super::{
     beatmap::{BeatmapExtended, Beatmapset},
+    cursor::{ScoreFeedCheckpoint, ScoresCursor},
     mods::GameMods,
     serde_util,
     user::User,
@@ -47,21 +52,68 @@ pub struct ProcessedScores {
     #[serde(default)]
     pub(crate) mode: Option<GameMode>,
     #[serde(rename = "cursor_string")]
-    pub(crate) cursor: Box<str>,
+    pub(crate) cursor: ScoresCursor,
 }
 
 impl ProcessedScores {
+    /// The cursor to request the next batch of scores.
+    ///
+    /// Can be stored and passed to [`GetScores::cursor`] later on.
+    #[inline]
+    pub const fn cursor(&self) -> &ScoresCursor {
+        &self.cursor
+    }
+
     /// Fetch the next batch of scores.
     #[inline]
     pub async fn get_next(&self, osu: &Osu) -> OsuResult<Self> {
-        let mut req = osu.scores().cursor(self.cursor.clone());
+        self.next_request(osu).await
+    }
 
-        if let Some(mode) = self.mode {
-            req = req.mode(mode);
+    fn next_request<'a>(&self, osu: &'a Osu) -> GetScores<'a> {
+        let req = osu.scores().cursor(self.cursor.clone());
+
+        match self.mode {
+            Some(mode) => req.mode(mode),
+            None => req,
         }
+    }
+
+    /// Turn these scores into a [`Stream`](futures::Stream) of all processed
+    /// scores, including those on subsequent batches.
+    ///
+    /// The stream ends once a batch contains no scores.
+    #[inline]
+    pub fn into_stream(self, osu: &Osu) -> Paginator<'_, Self> {
+        Paginator::new(osu, self)
+    }
+}
+
+impl Paginated for ProcessedScores {
+    type Item = Score;
+
+    fn next_page<'a>(&self, osu: &'a Osu) -> Option<PageFuture<'a, Self>> {
+        let req = self.next_request(osu);
+
+        Some(Box::pin(async move { req.await }))
+    }
 
-        req.await
+    fn items(&self) -> &[Self::Item] {
+        &self.scores
     }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.scores
+    }
+}
+
+/// A batch of new scores yielded by a [`ScoreFeed`](crate::request::ScoreFeed).
+#[derive(Clone, Debug)]
+pub struct ScoreFeedBatch {
+    /// Scores that were not yielded in a previous batch
+    pub scores: Vec<Score>,
+    /// Checkpoint to resume the feed after this batch
+    pub checkpoint: ScoreFeedCheckpoint,
 }
 
 impl ContainedUsers for ProcessedScores {
@@ -191,6 +243,17 @@ impl<'de> Deserialize<'de> for Score {
             // TODO: This is just a temporary fix for <https://github.com/ppy/osu-web/issues/10932>.
             // Once the issue is resolved, `Option<ScoreWeight>` can be used again.
             weight: Option<MaybeWeight>,
+            // Only available in multiplayer scores
+            #[serde(rename = "playlist_item_id")]
+            _playlist_item_id: Option<IgnoredAny>,
+            #[serde(rename = "room_id")]
+            _room_id: Option<IgnoredAny>,
+            #[serde(rename = "solo_score_id")]
+            _solo_score_id: Option<IgnoredAny>,
+            #[serde(rename = "position")]
+            _position: Option<IgnoredAny>,
+            #[serde(rename = "scores_around")]
+            _scores_around: Option<IgnoredAny>,
         }
 
         #[derive(Deserialize)]
@@ -1,4 +1,4 @@
-use super::{serde_util, CacheUserFn, ContainedUsers, GameMode};
+use super::{serde_util, CacheUserFn, ContainedUsers, GameMode, StaleNameFn};
 
 use serde::{
     de::{Error, IgnoredAny, MapAccess, SeqAccess, Visitor},
@@ -504,6 +504,15 @@ impl ContainedUsers for UserExtended {
     fn apply_to_users(&self, f: impl CacheUserFn) {
         f(self.user_id, &self.username);
     }
+
+    fn apply_to_stale_names(&self, f: impl StaleNameFn) {
+        let previous = self.previous_usernames.iter().flatten();
+
+        // The current name might have been used before
+        for name in previous.filter(|name| !name.eq_ignore_ascii_case(&self.username)) {
+            f(self.user_id, name);
+        }
+    }
 }
 
 /// Mainly used for embedding in certain responses to save additional api lookups.
@@ -0,0 +1,215 @@
+use std::{
+    future::{Future, IntoFuture},
+    pin::Pin,
+    task::{Context, Poll},
+    vec::IntoIter,
+};
+
+use futures::{
+    stream::{FuturesUnordered, StreamExt},
+    Stream,
+};
+
+use crate::OsuResult;
+
+type BatchItemFuture<'a, T> = Pin<Box<dyn Future<Output = (usize, OsuResult<T>)> + Send + 'a>>;
+
+/// Turns a request into its future once it's its turn to run.
+type QueuedRequest<'a, T> = Box<dyn FnOnce() -> BatchItemFuture<'a, T> + Send + 'a>;
+
+/// Future that resolves to the results of a [`Batch`] in input order.
+pub type BatchFuture<'a, T> = Pin<Box<dyn Future<Output = Vec<OsuResult<T>>> + Send + 'a>>;
+
+/// A collection of requests that are executed with a limited amount of
+/// concurrency.
+///
+/// Created through [`Osu::batch`](crate::Osu::batch). All requests go through
+/// the client's ratelimiter like any other request.
+///
+/// Requests of different types can be combined by pushing them into a common
+/// output type `T` that implements [`From`] for each of their outputs.
+///
+/// Awaiting the batch resolves to the results in the order the requests were
+/// pushed. Use [`Batch::unordered`] to receive the results as they complete
+/// and to be able to cancel the remaining requests.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rosu_v2::prelude::*;
+///
+/// # let _ = async {
+/// # let osu: Osu = unimplemented!();
+/// # let user_ids = [2, 3];
+/// let mut batch = osu.batch().concurrency(4);
+/// batch.extend(user_ids.into_iter().map(|user_id| osu.user(user_id)));
+///
+/// for (user_id, res) in user_ids.into_iter().zip(batch.await) {
+///     match res {
+///         Ok(user) => println!("{}", user.username),
+///         Err(err) => println!("failed to refresh user {user_id}: {err}"),
+///     }
+/// }
+/// # };
+/// ```
+#[must_use = "batches do nothing unless awaited or polled"]
+pub struct Batch<'a, T> {
+    requests: Vec<QueuedRequest<'a, T>>,
+    concurrency: usize,
+}
+
+impl<'a, T: Send + 'a> Batch<'a, T> {
+    const DEFAULT_CONCURRENCY: usize = 10;
+
+    pub(crate) const fn new() -> Self {
+        Self {
+            requests: Vec::new(),
+            concurrency: Self::DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// Specify how many requests may be in flight at once. Defaults to 10.
+    ///
+    /// A value of 0 is treated as 1.
+    pub const fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = if concurrency == 0 { 1 } else { concurrency };
+
+        self
+    }
+
+    /// Add a request whose output can be converted into `T`.
+    ///
+    /// The request is not sent until the batch is awaited or polled.
+    pub fn push<R, O>(mut self, request: R) -> Self
+    where
+        R: IntoFuture<Output = OsuResult<O>> + Send + 'a,
+        R::IntoFuture: Send + 'a,
+        O: Into<T>,
+    {
+        self.push_request(request);
+
+        self
+    }
+
+    /// The amount of requests in this batch.
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Whether this batch contains no requests.
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Execute the requests and yield their results as they complete.
+    ///
+    /// Each result is paired with the index of its request.
+    pub fn unordered(self) -> BatchStream<'a, T> {
+        BatchStream {
+            queued: self.requests.into_iter(),
+            running: FuturesUnordered::new(),
+            concurrency: self.concurrency,
+        }
+    }
+
+    fn push_request<R, O>(&mut self, request: R)
+    where
+        R: IntoFuture<Output = OsuResult<O>> + Send + 'a,
+        R::IntoFuture: Send + 'a,
+        O: Into<T>,
+    {
+        let idx = self.requests.len();
+
+        self.requests.push(Box::new(move || {
+            let fut = request.into_future();
+
+            Box::pin(async move { (idx, fut.await.map(O::into)) })
+        }));
+    }
+}
+
+impl<'a, T, R> Extend<R> for Batch<'a, T>
+where
+    T: Send + 'a,
+    R: IntoFuture<Output = OsuResult<T>> + Send + 'a,
+    R::IntoFuture: Send + 'a,
+{
+    fn extend<I: IntoIterator<Item = R>>(&mut self, requests: I) {
+        for request in requests {
+            self.push_request(request);
+        }
+    }
+}
+
+impl<'a, T: Send + 'a> IntoFuture for Batch<'a, T> {
+    type Output = Vec<OsuResult<T>>;
+    type IntoFuture = BatchFuture<'a, T>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let len = self.len();
+        let mut stream = self.unordered();
+
+        Box::pin(async move {
+            let mut results: Vec<_> = (0..len).map(|_| None).collect();
+
+            while let Some((idx, res)) = stream.next().await {
+                results[idx] = Some(res);
+            }
+
+            results.into_iter().flatten().collect()
+        })
+    }
+}
+
+/// A [`Stream`] yielding the results of a [`Batch`] as they complete.
+///
+/// Created through [`Batch::unordered`]. Each item contains the index of its
+/// request within the batch. Dropping the stream or calling
+/// [`BatchStream::cancel`] aborts all remaining requests.
+#[must_use = "streams do nothing unless polled"]
+pub struct BatchStream<'a, T> {
+    queued: IntoIter<QueuedRequest<'a, T>>,
+    running: FuturesUnordered<BatchItemFuture<'a, T>>,
+    concurrency: usize,
+}
+
+impl<T> BatchStream<'_, T> {
+    /// Abort all requests that are in flight or not yet sent.
+    ///
+    /// The stream ends afterwards.
+    pub fn cancel(&mut self) {
+        self.queued = Vec::new().into_iter();
+        self.running.clear();
+    }
+
+    /// The amount of requests whose results were not yet yielded.
+    pub fn remaining(&self) -> usize {
+        self.queued.len() + self.running.len()
+    }
+}
+
+impl<T> Unpin for BatchStream<'_, T> {}
+
+impl<T> Stream for BatchStream<'_, T> {
+    type Item = (usize, OsuResult<T>);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        while this.running.len() < this.concurrency {
+            // Only create the future once the request may run
+            let Some(request) = this.queued.next() else {
+                break;
+            };
+            this.running.push(request());
+        }
+
+        this.running.poll_next_unpin(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+
+        (remaining, Some(remaining))
+    }
+}
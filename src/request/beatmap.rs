@@ -2,26 +2,36 @@ use crate::{
     model::{
         beatmap::{
             Beatmap, BeatmapDifficultyAttributes, BeatmapDifficultyAttributesWrapper,
-            BeatmapExtended, BeatmapScores, BeatmapsetEvents, BeatmapsetExtended,
+            BeatmapExtended, BeatmapPack, BeatmapPackType, BeatmapPacks, BeatmapScores,
+            BeatmapsetDiscussionMapsetStatus, BeatmapsetDiscussionMessageType,
+            BeatmapsetDiscussionPosts, BeatmapsetDiscussionSort, BeatmapsetDiscussionVotes,
+            BeatmapsetDiscussions, BeatmapsetEvents, BeatmapsetExtended, BeatmapsetPostType,
             BeatmapsetSearchParameters, BeatmapsetSearchResult, BeatmapsetSearchSort, Genre,
             Language, RankStatus, SearchRankStatus,
         },
+        cursor::{
+            BeatmapPacksCursor, BeatmapsetDiscussionPostsCursor, BeatmapsetDiscussionVotesCursor,
+            BeatmapsetDiscussionsCursor, BeatmapsetSearchCursor,
+        },
         score::{BeatmapUserScore, Score},
-        DeserializedList, GameMode,
+        BulkLookup, DeserializedList, GameMode,
     },
     prelude::GameModsIntermode,
     request::{
-        serialize::{maybe_mode_as_str, maybe_mods_as_list},
+        bulk::{self, BulkFuture},
+        serialize::{
+            maybe_mode_as_str, maybe_mods_as_list, message_types_as_list, post_types_as_list,
+        },
         Query, Request,
     },
     routing::Route,
-    Osu,
+    Osu, OsuResult,
 };
 
 use itoa::Buffer;
 use serde::ser::SerializeMap;
 use serde::{Serialize, Serializer};
-use std::fmt::Write;
+use std::{fmt::Write, future::IntoFuture};
 
 use super::{JsonBody, UserId};
 
@@ -117,6 +127,37 @@ into_future! {
     }
 }
 
+/// Get a [`BulkLookup`] of [`Beatmap`]s for any amount of map ids.
+#[must_use = "requests must be configured and executed"]
+pub struct GetBeatmapsBulk<'a> {
+    osu: &'a Osu,
+    map_ids: Vec<u32>,
+}
+
+impl<'a> GetBeatmapsBulk<'a> {
+    pub(crate) fn new<I>(osu: &'a Osu, map_ids: I) -> Self
+    where
+        I: IntoIterator<Item = u32>,
+    {
+        Self {
+            osu,
+            map_ids: bulk::unique_ids(map_ids),
+        }
+    }
+}
+
+impl<'a> IntoFuture for GetBeatmapsBulk<'a> {
+    type Output = OsuResult<BulkLookup<Beatmap>>;
+    type IntoFuture = BulkFuture<'a, Beatmap>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let Self { osu, map_ids } = self;
+        let fetch = move |chunk: &[u32]| GetBeatmaps::new(osu, chunk.iter().copied()).into_future();
+
+        Box::pin(bulk::fetch_chunked(map_ids, fetch, |map| map.map_id))
+    }
+}
+
 /// Get [`BeatmapDifficultyAttributes`] of a map.
 #[must_use = "requests must be configured and executed"]
 pub struct GetBeatmapDifficultyAttributes<'a> {
@@ -487,6 +528,96 @@ into_future! {
     }
 }
 
+/// Get a [`BeatmapPack`] including its mapsets.
+#[must_use = "requests must be configured and executed"]
+#[derive(Serialize)]
+pub struct GetBeatmapPack<'a> {
+    #[serde(skip)]
+    osu: &'a Osu,
+    #[serde(skip)]
+    tag: Box<str>,
+    legacy_only: bool,
+}
+
+impl<'a> GetBeatmapPack<'a> {
+    pub(crate) fn new(osu: &'a Osu, tag: impl Into<Box<str>>) -> Self {
+        Self {
+            osu,
+            tag: tag.into(),
+            legacy_only: false,
+        }
+    }
+
+    /// Whether or not to only consider legacy scores for the user's
+    /// completion of the pack.
+    #[inline]
+    pub const fn legacy_only(mut self, legacy_only: bool) -> Self {
+        self.legacy_only = legacy_only;
+
+        self
+    }
+}
+
+into_future! {
+    |self: GetBeatmapPack<'_>| -> BeatmapPack {
+        let query = Query::encode(&self);
+        let route = Route::GetBeatmapPack { tag: self.tag };
+
+        Request::with_query(route, query)
+    }
+}
+
+/// Get [`BeatmapPacks`] of a [`BeatmapPackType`].
+#[must_use = "requests must be configured and executed"]
+#[derive(Serialize)]
+pub struct GetBeatmapPacks<'a> {
+    #[serde(skip)]
+    osu: &'a Osu,
+    #[serde(rename = "type")]
+    kind: Option<BeatmapPackType>,
+    #[serde(rename = "cursor_string")]
+    cursor: Option<BeatmapPacksCursor>,
+}
+
+impl<'a> GetBeatmapPacks<'a> {
+    pub(crate) const fn new(osu: &'a Osu) -> Self {
+        Self {
+            osu,
+            kind: None,
+            cursor: None,
+        }
+    }
+
+    /// Specify the type of packs. Defaults to [`BeatmapPackType::Standard`].
+    #[inline]
+    pub const fn kind(mut self, kind: BeatmapPackType) -> Self {
+        self.kind = Some(kind);
+
+        self
+    }
+
+    /// Continue from the given cursor, e.g. obtained through
+    /// [`BeatmapPacks::cursor`].
+    #[inline]
+    pub fn cursor(mut self, cursor: BeatmapPacksCursor) -> Self {
+        self.cursor = Some(cursor);
+
+        self
+    }
+}
+
+into_future! {
+    |self: GetBeatmapPacks<'_>| -> BeatmapPacks {
+        let kind = self.kind;
+
+        (Request::with_query(Route::GetBeatmapPacks, Query::encode(&self)), kind)
+    } => |packs, kind: Option<BeatmapPackType>| -> BeatmapPacks {
+        packs.kind = kind;
+
+        Ok(packs)
+    }
+}
+
 /// Get a [`BeatmapsetExtended`].
 #[must_use = "requests must be configured and executed"]
 pub struct GetBeatmapset<'a> {
@@ -548,6 +679,560 @@ into_future! {
     }
 }
 
+/// Get [`BeatmapsetDiscussions`].
+#[must_use = "requests must be configured and executed"]
+#[derive(Serialize)]
+pub struct GetBeatmapsetDiscussions<'a> {
+    #[serde(skip)]
+    osu: &'a Osu,
+    #[serde(flatten)]
+    params: BeatmapsetDiscussionsParams,
+    #[serde(rename = "cursor_string")]
+    cursor: Option<BeatmapsetDiscussionsCursor>,
+}
+
+/// Filters of a [`GetBeatmapsetDiscussions`] request.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub(crate) struct BeatmapsetDiscussionsParams {
+    #[serde(rename = "beatmap_id")]
+    map_id: Option<u32>,
+    #[serde(rename = "beatmapset_id")]
+    mapset_id: Option<u32>,
+    #[serde(rename = "beatmapset_status")]
+    mapset_status: Option<BeatmapsetDiscussionMapsetStatus>,
+    limit: Option<usize>,
+    #[serde(flatten, serialize_with = "message_types_as_list")]
+    message_types: Vec<BeatmapsetDiscussionMessageType>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    only_unresolved: bool,
+    sort: Option<BeatmapsetDiscussionSort>,
+    #[serde(rename = "user")]
+    user_id: Option<u32>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    with_deleted: bool,
+}
+
+/// [`BeatmapsetDiscussionsParams`] as tuple of public types so that they can
+/// be passed on to post processing.
+type BeatmapsetDiscussionsParamsParts = (
+    Option<u32>,
+    Option<u32>,
+    Option<BeatmapsetDiscussionMapsetStatus>,
+    Option<usize>,
+    Vec<BeatmapsetDiscussionMessageType>,
+    bool,
+    Option<BeatmapsetDiscussionSort>,
+    Option<u32>,
+    bool,
+);
+
+impl From<BeatmapsetDiscussionsParams> for BeatmapsetDiscussionsParamsParts {
+    fn from(params: BeatmapsetDiscussionsParams) -> Self {
+        let BeatmapsetDiscussionsParams {
+            map_id,
+            mapset_id,
+            mapset_status,
+            limit,
+            message_types,
+            only_unresolved,
+            sort,
+            user_id,
+            with_deleted,
+        } = params;
+
+        (
+            map_id,
+            mapset_id,
+            mapset_status,
+            limit,
+            message_types,
+            only_unresolved,
+            sort,
+            user_id,
+            with_deleted,
+        )
+    }
+}
+
+impl From<BeatmapsetDiscussionsParamsParts> for BeatmapsetDiscussionsParams {
+    fn from(parts: BeatmapsetDiscussionsParamsParts) -> Self {
+        let (
+            map_id,
+            mapset_id,
+            mapset_status,
+            limit,
+            message_types,
+            only_unresolved,
+            sort,
+            user_id,
+            with_deleted,
+        ) = parts;
+
+        Self {
+            map_id,
+            mapset_id,
+            mapset_status,
+            limit,
+            message_types,
+            only_unresolved,
+            sort,
+            user_id,
+            with_deleted,
+        }
+    }
+}
+
+impl<'a> GetBeatmapsetDiscussions<'a> {
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self::with_params(osu, BeatmapsetDiscussionsParams::default())
+    }
+
+    pub(crate) const fn with_params(osu: &'a Osu, params: BeatmapsetDiscussionsParams) -> Self {
+        Self {
+            osu,
+            params,
+            cursor: None,
+        }
+    }
+
+    /// Only get discussions of the given map.
+    #[inline]
+    pub const fn map_id(mut self, map_id: u32) -> Self {
+        self.params.map_id = Some(map_id);
+
+        self
+    }
+
+    /// Only get discussions of the given mapset.
+    #[inline]
+    pub const fn mapset_id(mut self, mapset_id: u32) -> Self {
+        self.params.mapset_id = Some(mapset_id);
+
+        self
+    }
+
+    /// Only get discussions of mapsets with the given status. Defaults to
+    /// [`BeatmapsetDiscussionMapsetStatus::All`].
+    #[inline]
+    pub const fn mapset_status(mut self, status: BeatmapsetDiscussionMapsetStatus) -> Self {
+        self.params.mapset_status = Some(status);
+
+        self
+    }
+
+    /// Limit the amount of discussions per page.
+    #[inline]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.params.limit = Some(limit);
+
+        self
+    }
+
+    /// Only get discussions of the given message types.
+    #[inline]
+    pub fn message_types(
+        mut self,
+        message_types: impl IntoIterator<Item = BeatmapsetDiscussionMessageType>,
+    ) -> Self {
+        self.params.message_types = message_types.into_iter().collect();
+
+        self
+    }
+
+    /// Only get unresolved discussions.
+    #[inline]
+    pub const fn only_unresolved(mut self, only_unresolved: bool) -> Self {
+        self.params.only_unresolved = only_unresolved;
+
+        self
+    }
+
+    /// Specify the sort order. Defaults to
+    /// [`BeatmapsetDiscussionSort::IdDescending`].
+    #[inline]
+    pub const fn sort(mut self, sort: BeatmapsetDiscussionSort) -> Self {
+        self.params.sort = Some(sort);
+
+        self
+    }
+
+    /// Only get discussions started by the given user.
+    #[inline]
+    pub const fn user_id(mut self, user_id: u32) -> Self {
+        self.params.user_id = Some(user_id);
+
+        self
+    }
+
+    /// Whether or not to include deleted discussions. Requires the current
+    /// user to have permission to see them.
+    #[inline]
+    pub const fn with_deleted(mut self, with_deleted: bool) -> Self {
+        self.params.with_deleted = with_deleted;
+
+        self
+    }
+
+    /// Continue from the given cursor, e.g. obtained through
+    /// [`BeatmapsetDiscussions::cursor`].
+    ///
+    /// Be sure all other parameters are the same as for the request that
+    /// provided the cursor.
+    #[inline]
+    pub fn cursor(mut self, cursor: BeatmapsetDiscussionsCursor) -> Self {
+        self.cursor = Some(cursor);
+
+        self
+    }
+}
+
+into_future! {
+    |self: GetBeatmapsetDiscussions<'_>| -> BeatmapsetDiscussions {
+        let params = BeatmapsetDiscussionsParamsParts::from(self.params.clone());
+
+        (Request::with_query(Route::GetBeatmapsetDiscussions, Query::encode(&self)), params)
+    } => |discussions, params: BeatmapsetDiscussionsParamsParts| -> BeatmapsetDiscussions {
+        discussions.params = Some(params.into());
+
+        Ok(discussions)
+    }
+}
+
+/// Get [`BeatmapsetDiscussionPosts`].
+#[must_use = "requests must be configured and executed"]
+#[derive(Serialize)]
+pub struct GetBeatmapsetDiscussionPosts<'a> {
+    #[serde(skip)]
+    osu: &'a Osu,
+    #[serde(flatten)]
+    params: BeatmapsetDiscussionPostsParams,
+    #[serde(rename = "cursor_string")]
+    cursor: Option<BeatmapsetDiscussionPostsCursor>,
+}
+
+/// Filters of a [`GetBeatmapsetDiscussionPosts`] request.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub(crate) struct BeatmapsetDiscussionPostsParams {
+    #[serde(rename = "beatmapset_discussion_id")]
+    discussion_id: Option<u64>,
+    limit: Option<usize>,
+    sort: Option<BeatmapsetDiscussionSort>,
+    #[serde(flatten, serialize_with = "post_types_as_list")]
+    types: Vec<BeatmapsetPostType>,
+    #[serde(rename = "user")]
+    user_id: Option<u32>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    with_deleted: bool,
+}
+
+/// [`BeatmapsetDiscussionPostsParams`] as tuple of public types so that they
+/// can be passed on to post processing.
+type BeatmapsetDiscussionPostsParamsParts = (
+    Option<u64>,
+    Option<usize>,
+    Option<BeatmapsetDiscussionSort>,
+    Vec<BeatmapsetPostType>,
+    Option<u32>,
+    bool,
+);
+
+impl From<BeatmapsetDiscussionPostsParams> for BeatmapsetDiscussionPostsParamsParts {
+    fn from(params: BeatmapsetDiscussionPostsParams) -> Self {
+        let BeatmapsetDiscussionPostsParams {
+            discussion_id,
+            limit,
+            sort,
+            types,
+            user_id,
+            with_deleted,
+        } = params;
+
+        (discussion_id, limit, sort, types, user_id, with_deleted)
+    }
+}
+
+impl From<BeatmapsetDiscussionPostsParamsParts> for BeatmapsetDiscussionPostsParams {
+    fn from(parts: BeatmapsetDiscussionPostsParamsParts) -> Self {
+        let (discussion_id, limit, sort, types, user_id, with_deleted) = parts;
+
+        Self {
+            discussion_id,
+            limit,
+            sort,
+            types,
+            user_id,
+            with_deleted,
+        }
+    }
+}
+
+impl<'a> GetBeatmapsetDiscussionPosts<'a> {
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self::with_params(osu, BeatmapsetDiscussionPostsParams::default())
+    }
+
+    pub(crate) const fn with_params(osu: &'a Osu, params: BeatmapsetDiscussionPostsParams) -> Self {
+        Self {
+            osu,
+            params,
+            cursor: None,
+        }
+    }
+
+    /// Only get posts of the given discussion.
+    #[inline]
+    pub const fn discussion_id(mut self, discussion_id: u64) -> Self {
+        self.params.discussion_id = Some(discussion_id);
+
+        self
+    }
+
+    /// Limit the amount of posts per page.
+    #[inline]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.params.limit = Some(limit);
+
+        self
+    }
+
+    /// Specify the sort order. Defaults to
+    /// [`BeatmapsetDiscussionSort::IdDescending`].
+    #[inline]
+    pub const fn sort(mut self, sort: BeatmapsetDiscussionSort) -> Self {
+        self.params.sort = Some(sort);
+
+        self
+    }
+
+    /// Only get posts of the given types. Defaults to
+    /// [`BeatmapsetPostType::Reply`].
+    #[inline]
+    pub fn types(mut self, types: impl IntoIterator<Item = BeatmapsetPostType>) -> Self {
+        self.params.types = types.into_iter().collect();
+
+        self
+    }
+
+    /// Only get posts of the given user.
+    #[inline]
+    pub const fn user_id(mut self, user_id: u32) -> Self {
+        self.params.user_id = Some(user_id);
+
+        self
+    }
+
+    /// Whether or not to include deleted posts. Requires the current user to
+    /// have permission to see them.
+    #[inline]
+    pub const fn with_deleted(mut self, with_deleted: bool) -> Self {
+        self.params.with_deleted = with_deleted;
+
+        self
+    }
+
+    /// Continue from the given cursor, e.g. obtained through
+    /// [`BeatmapsetDiscussionPosts::cursor`].
+    ///
+    /// Be sure all other parameters are the same as for the request that
+    /// provided the cursor.
+    #[inline]
+    pub fn cursor(mut self, cursor: BeatmapsetDiscussionPostsCursor) -> Self {
+        self.cursor = Some(cursor);
+
+        self
+    }
+}
+
+into_future! {
+    |self: GetBeatmapsetDiscussionPosts<'_>| -> BeatmapsetDiscussionPosts {
+        let params = BeatmapsetDiscussionPostsParamsParts::from(self.params.clone());
+        let query = Query::encode(&self);
+
+        (Request::with_query(Route::GetBeatmapsetDiscussionPosts, query), params)
+    } => |posts, params: BeatmapsetDiscussionPostsParamsParts| -> BeatmapsetDiscussionPosts {
+        posts.params = Some(params.into());
+
+        Ok(posts)
+    }
+}
+
+/// Get [`BeatmapsetDiscussionVotes`].
+#[must_use = "requests must be configured and executed"]
+#[derive(Serialize)]
+pub struct GetBeatmapsetDiscussionVotes<'a> {
+    #[serde(skip)]
+    osu: &'a Osu,
+    #[serde(flatten)]
+    params: BeatmapsetDiscussionVotesParams,
+    #[serde(rename = "cursor_string")]
+    cursor: Option<BeatmapsetDiscussionVotesCursor>,
+}
+
+/// Filters of a [`GetBeatmapsetDiscussionVotes`] request.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub(crate) struct BeatmapsetDiscussionVotesParams {
+    #[serde(rename = "beatmapset_discussion_id")]
+    discussion_id: Option<u64>,
+    limit: Option<usize>,
+    #[serde(rename = "receiver")]
+    receiver_id: Option<u32>,
+    score: Option<i32>,
+    sort: Option<BeatmapsetDiscussionSort>,
+    #[serde(rename = "user")]
+    user_id: Option<u32>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    with_deleted: bool,
+}
+
+/// [`BeatmapsetDiscussionVotesParams`] as tuple of public types so that they
+/// can be passed on to post processing.
+type BeatmapsetDiscussionVotesParamsParts = (
+    Option<u64>,
+    Option<usize>,
+    Option<u32>,
+    Option<i32>,
+    Option<BeatmapsetDiscussionSort>,
+    Option<u32>,
+    bool,
+);
+
+impl From<BeatmapsetDiscussionVotesParams> for BeatmapsetDiscussionVotesParamsParts {
+    fn from(params: BeatmapsetDiscussionVotesParams) -> Self {
+        let BeatmapsetDiscussionVotesParams {
+            discussion_id,
+            limit,
+            receiver_id,
+            score,
+            sort,
+            user_id,
+            with_deleted,
+        } = params;
+
+        (
+            discussion_id,
+            limit,
+            receiver_id,
+            score,
+            sort,
+            user_id,
+            with_deleted,
+        )
+    }
+}
+
+impl From<BeatmapsetDiscussionVotesParamsParts> for BeatmapsetDiscussionVotesParams {
+    fn from(parts: BeatmapsetDiscussionVotesParamsParts) -> Self {
+        let (discussion_id, limit, receiver_id, score, sort, user_id, with_deleted) = parts;
+
+        Self {
+            discussion_id,
+            limit,
+            receiver_id,
+            score,
+            sort,
+            user_id,
+            with_deleted,
+        }
+    }
+}
+
+impl<'a> GetBeatmapsetDiscussionVotes<'a> {
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self::with_params(osu, BeatmapsetDiscussionVotesParams::default())
+    }
+
+    pub(crate) const fn with_params(osu: &'a Osu, params: BeatmapsetDiscussionVotesParams) -> Self {
+        Self {
+            osu,
+            params,
+            cursor: None,
+        }
+    }
+
+    /// Only get votes of the given discussion.
+    #[inline]
+    pub const fn discussion_id(mut self, discussion_id: u64) -> Self {
+        self.params.discussion_id = Some(discussion_id);
+
+        self
+    }
+
+    /// Limit the amount of votes per page.
+    #[inline]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.params.limit = Some(limit);
+
+        self
+    }
+
+    /// Only get votes on discussions of the given user.
+    #[inline]
+    pub const fn receiver_id(mut self, receiver_id: u32) -> Self {
+        self.params.receiver_id = Some(receiver_id);
+
+        self
+    }
+
+    /// Only get upvotes if `true` or downvotes if `false`.
+    #[inline]
+    pub const fn upvotes(mut self, upvotes: bool) -> Self {
+        self.params.score = Some(if upvotes { 1 } else { -1 });
+
+        self
+    }
+
+    /// Specify the sort order. Defaults to
+    /// [`BeatmapsetDiscussionSort::IdDescending`].
+    #[inline]
+    pub const fn sort(mut self, sort: BeatmapsetDiscussionSort) -> Self {
+        self.params.sort = Some(sort);
+
+        self
+    }
+
+    /// Only get votes of the given user.
+    #[inline]
+    pub const fn user_id(mut self, user_id: u32) -> Self {
+        self.params.user_id = Some(user_id);
+
+        self
+    }
+
+    /// Whether or not to include deleted votes. Requires the current user to
+    /// have permission to see them.
+    #[inline]
+    pub const fn with_deleted(mut self, with_deleted: bool) -> Self {
+        self.params.with_deleted = with_deleted;
+
+        self
+    }
+
+    /// Continue from the given cursor, e.g. obtained through
+    /// [`BeatmapsetDiscussionVotes::cursor`].
+    ///
+    /// Be sure all other parameters are the same as for the request that
+    /// provided the cursor.
+    #[inline]
+    pub fn cursor(mut self, cursor: BeatmapsetDiscussionVotesCursor) -> Self {
+        self.cursor = Some(cursor);
+
+        self
+    }
+}
+
+into_future! {
+    |self: GetBeatmapsetDiscussionVotes<'_>| -> BeatmapsetDiscussionVotes {
+        let params = BeatmapsetDiscussionVotesParamsParts::from(self.params.clone());
+        let query = Query::encode(&self);
+
+        (Request::with_query(Route::GetBeatmapsetDiscussionVotes, query), params)
+    } => |votes, params: BeatmapsetDiscussionVotesParamsParts| -> BeatmapsetDiscussionVotes {
+        votes.params = Some(params.into());
+
+        Ok(votes)
+    }
+}
+
 /// Get a [`BeatmapsetSearchResult`] containing the maps that fit the search
 /// query.
 ///
@@ -585,7 +1270,7 @@ pub struct GetBeatmapsetSearch<'a> {
     sort: Option<BeatmapsetSearchSort>,
     descending: bool,
     page: Option<u32>,
-    cursor: Option<&'a str>,
+    cursor: Option<BeatmapsetSearchCursor>,
 }
 
 impl<'a> GetBeatmapsetSearch<'a> {
@@ -741,8 +1426,13 @@ impl<'a> GetBeatmapsetSearch<'a> {
         self
     }
 
+    /// Continue from the given cursor, e.g. obtained through
+    /// [`BeatmapsetSearchResult::cursor`].
+    ///
+    /// All other parameters should be the same as for the request that
+    /// provided the cursor.
     #[inline]
-    pub(crate) const fn cursor(mut self, cursor: &'a str) -> Self {
+    pub fn cursor(mut self, cursor: BeatmapsetSearchCursor) -> Self {
         self.cursor = Some(cursor);
 
         self
@@ -920,7 +1610,7 @@ impl Serialize for GetBeatmapsetSearch<'_> {
             map.serialize_entry("page", page)?;
         }
 
-        if let Some(cursor) = self.cursor {
+        if let Some(ref cursor) = self.cursor {
             map.serialize_entry("cursor_string", cursor)?;
         }
 
@@ -0,0 +1,57 @@
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    pin::Pin,
+};
+
+use futures::future::try_join_all;
+
+use crate::{model::BulkLookup, OsuResult};
+
+/// Future that resolves to a [`BulkLookup`].
+pub type BulkFuture<'a, T> = Pin<Box<dyn Future<Output = OsuResult<BulkLookup<T>>> + Send + 'a>>;
+
+/// The maximum amount of ids the osu!api accepts for a single request.
+pub(crate) const CHUNK_SIZE: usize = 50;
+
+/// Collect the ids while removing duplicates but keeping their order.
+pub(crate) fn unique_ids(ids: impl IntoIterator<Item = u32>) -> Vec<u32> {
+    let mut ids: Vec<_> = ids.into_iter().collect();
+    let mut seen = HashSet::with_capacity(ids.len());
+    ids.retain(|id| seen.insert(*id));
+
+    ids
+}
+
+/// Fetch the ids in concurrent chunks and merge the results.
+///
+/// Fails as soon as any chunk fails, discarding the other chunks.
+pub(crate) async fn fetch_chunked<T, F, Fut>(
+    ids: Vec<u32>,
+    fetch: F,
+    id_of: fn(&T) -> u32,
+) -> OsuResult<BulkLookup<T>>
+where
+    F: FnMut(&[u32]) -> Fut,
+    Fut: Future<Output = OsuResult<Vec<T>>>,
+{
+    let chunks = try_join_all(ids.chunks(CHUNK_SIZE).map(fetch)).await?;
+
+    let mut found: HashMap<_, _> = chunks
+        .into_iter()
+        .flatten()
+        .map(|item| (id_of(&item), item))
+        .collect();
+
+    let mut items = Vec::with_capacity(found.len());
+    let mut missing = Vec::new();
+
+    for id in ids {
+        match found.remove(&id) {
+            Some(item) => items.push(item),
+            None => missing.push(id),
+        }
+    }
+
+    Ok(BulkLookup { items, missing })
+}
@@ -0,0 +1,301 @@
+use serde::Serialize;
+
+use crate::{
+    future::BytesWrap,
+    model::chat::{ChatAck, ChatChannel, ChatChannelDetails, ChatMessage, ChatPrivateMessage},
+    routing::Route,
+    Osu,
+};
+
+use super::{JsonBody, Query, Request};
+
+/// Get a vec of joinable public [`ChatChannel`]s.
+#[must_use = "requests must be configured and executed"]
+pub struct GetChatChannels<'a> {
+    osu: &'a Osu,
+}
+
+impl<'a> GetChatChannels<'a> {
+    pub(crate) const fn new(osu: &'a Osu) -> Self {
+        Self { osu }
+    }
+}
+
+into_future! {
+    |self: GetChatChannels<'_>| -> Vec<ChatChannel> {
+        Request::new(Route::GetChatChannels)
+    }
+}
+
+/// Get a [`ChatChannelDetails`] containing a channel and its members.
+#[must_use = "requests must be configured and executed"]
+pub struct GetChatChannel<'a> {
+    osu: &'a Osu,
+    channel_id: u64,
+}
+
+impl<'a> GetChatChannel<'a> {
+    pub(crate) const fn new(osu: &'a Osu, channel_id: u64) -> Self {
+        Self { osu, channel_id }
+    }
+}
+
+into_future! {
+    |self: GetChatChannel<'_>| -> ChatChannelDetails {
+        Request::new(Route::GetChatChannel {
+            channel_id: self.channel_id,
+        })
+    }
+}
+
+/// Join a [`ChatChannel`].
+#[must_use = "requests must be configured and executed"]
+pub struct JoinChatChannel<'a> {
+    osu: &'a Osu,
+    channel_id: u64,
+    user_id: u32,
+}
+
+impl<'a> JoinChatChannel<'a> {
+    pub(crate) const fn new(osu: &'a Osu, channel_id: u64, user_id: u32) -> Self {
+        Self {
+            osu,
+            channel_id,
+            user_id,
+        }
+    }
+}
+
+into_future! {
+    |self: JoinChatChannel<'_>| -> ChatChannel {
+        Request::new(Route::PutChatChannelUser {
+            channel_id: self.channel_id,
+            user_id: self.user_id,
+        })
+    }
+}
+
+/// Leave a [`ChatChannel`].
+#[must_use = "requests must be configured and executed"]
+pub struct LeaveChatChannel<'a> {
+    osu: &'a Osu,
+    channel_id: u64,
+    user_id: u32,
+}
+
+impl<'a> LeaveChatChannel<'a> {
+    pub(crate) const fn new(osu: &'a Osu, channel_id: u64, user_id: u32) -> Self {
+        Self {
+            osu,
+            channel_id,
+            user_id,
+        }
+    }
+}
+
+into_future! {
+    |self: LeaveChatChannel<'_>| -> BytesWrap {
+        Request::new(Route::DeleteChatChannelUser {
+            channel_id: self.channel_id,
+            user_id: self.user_id,
+        })
+    } => |_bytes, _| -> () {
+        Ok(())
+    }
+}
+
+/// Get a vec of [`ChatMessage`]s of a channel.
+#[must_use = "requests must be configured and executed"]
+#[derive(Serialize)]
+pub struct GetChatMessages<'a> {
+    #[serde(skip)]
+    osu: &'a Osu,
+    #[serde(skip)]
+    channel_id: u64,
+    limit: Option<usize>,
+    since: Option<u64>,
+    until: Option<u64>,
+}
+
+impl<'a> GetChatMessages<'a> {
+    pub(crate) const fn new(osu: &'a Osu, channel_id: u64) -> Self {
+        Self {
+            osu,
+            channel_id,
+            limit: None,
+            since: None,
+            until: None,
+        }
+    }
+
+    /// Limit the amount of messages, between 1 and 50. Defaults to 50.
+    #[inline]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+
+        self
+    }
+
+    /// Only get messages after the given message id.
+    #[inline]
+    pub const fn since(mut self, message_id: u64) -> Self {
+        self.since = Some(message_id);
+
+        self
+    }
+
+    /// Only get messages before the given message id.
+    #[inline]
+    pub const fn until(mut self, message_id: u64) -> Self {
+        self.until = Some(message_id);
+
+        self
+    }
+}
+
+into_future! {
+    |self: GetChatMessages<'_>| -> Vec<ChatMessage> {
+        let route = Route::GetChatMessages {
+            channel_id: self.channel_id,
+        };
+
+        Request::with_query(route, Query::encode(&self))
+    }
+}
+
+/// Send a [`ChatMessage`] to a channel.
+#[must_use = "requests must be configured and executed"]
+pub struct SendChatMessage<'a> {
+    osu: &'a Osu,
+    channel_id: u64,
+    message: String,
+    is_action: bool,
+}
+
+impl<'a> SendChatMessage<'a> {
+    pub(crate) const fn new(osu: &'a Osu, channel_id: u64, message: String) -> Self {
+        Self {
+            osu,
+            channel_id,
+            message,
+            is_action: false,
+        }
+    }
+
+    /// Send the message as action, i.e. `/me`.
+    #[inline]
+    pub const fn action(mut self, is_action: bool) -> Self {
+        self.is_action = is_action;
+
+        self
+    }
+}
+
+into_future! {
+    |self: SendChatMessage<'_>| -> ChatMessage {
+        let route = Route::PostChatMessage {
+            channel_id: self.channel_id,
+        };
+
+        let mut body = JsonBody::new();
+        body.push_str("message", &self.message);
+        body.push_bool("is_action", self.is_action);
+
+        Request::with_body(route, body)
+    }
+}
+
+/// Send a private message to a user, creating a new channel if necessary.
+#[must_use = "requests must be configured and executed"]
+pub struct SendPrivateMessage<'a> {
+    osu: &'a Osu,
+    target_id: u32,
+    message: String,
+    is_action: bool,
+    uuid: Option<String>,
+}
+
+impl<'a> SendPrivateMessage<'a> {
+    pub(crate) const fn new(osu: &'a Osu, target_id: u32, message: String) -> Self {
+        Self {
+            osu,
+            target_id,
+            message,
+            is_action: false,
+            uuid: None,
+        }
+    }
+
+    /// Send the message as action, i.e. `/me`.
+    #[inline]
+    pub const fn action(mut self, is_action: bool) -> Self {
+        self.is_action = is_action;
+
+        self
+    }
+
+    /// Specify a client-side id that will be relayed back in the response.
+    #[inline]
+    pub fn uuid(mut self, uuid: impl Into<String>) -> Self {
+        self.uuid = Some(uuid.into());
+
+        self
+    }
+}
+
+into_future! {
+    |self: SendPrivateMessage<'_>| -> ChatPrivateMessage {
+        let mut body = JsonBody::new();
+        body.push_int("target_id", self.target_id);
+        body.push_str("message", &self.message);
+        body.push_bool("is_action", self.is_action);
+
+        if let Some(ref uuid) = self.uuid {
+            body.push_str("uuid", uuid);
+        }
+
+        Request::with_body(Route::PostChatPrivateMessage, body)
+    }
+}
+
+/// Mark the chat as read and keep the chat session alive.
+#[must_use = "requests must be configured and executed"]
+#[derive(Serialize)]
+pub struct ChatAcknowledge<'a> {
+    #[serde(skip)]
+    osu: &'a Osu,
+    since: Option<u64>,
+    history_since: Option<u64>,
+}
+
+impl<'a> ChatAcknowledge<'a> {
+    pub(crate) const fn new(osu: &'a Osu) -> Self {
+        Self {
+            osu,
+            since: None,
+            history_since: None,
+        }
+    }
+
+    /// The id of the last received message.
+    #[inline]
+    pub const fn since(mut self, message_id: u64) -> Self {
+        self.since = Some(message_id);
+
+        self
+    }
+
+    /// Only include silences after the given silence id.
+    #[inline]
+    pub const fn history_since(mut self, silence_id: u64) -> Self {
+        self.history_since = Some(silence_id);
+
+        self
+    }
+}
+
+into_future! {
+    |self: ChatAcknowledge<'_>| -> ChatAck {
+        Request::with_query(Route::PostChatAck, Query::encode(&self))
+    }
+}
@@ -1,5 +1,8 @@
 use crate::{
-    model::comments::{CommentBundle, CommentSort},
+    model::{
+        comments::{CommentBundle, CommentSort},
+        cursor::CommentsCursor,
+    },
     request::{serialize::maybe_comment_sort, Query, Request},
     routing::Route,
     Osu,
@@ -20,7 +23,7 @@ pub struct GetComments<'a> {
     #[serde(serialize_with = "maybe_comment_sort")]
     sort: Option<CommentSort>,
     #[serde(rename = "cursor_string")]
-    cursor: Option<&'a str>,
+    cursor: Option<CommentsCursor>,
 }
 
 impl<'a> GetComments<'a> {
@@ -83,8 +86,9 @@ impl<'a> GetComments<'a> {
         self
     }
 
+    /// Continue from the given cursor, e.g. obtained through [`CommentBundle::cursor`].
     #[inline]
-    pub(crate) const fn cursor(mut self, cursor: &'a str) -> Self {
+    pub fn cursor(mut self, cursor: CommentsCursor) -> Self {
         self.cursor = Some(cursor);
 
         self
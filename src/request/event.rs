@@ -1,12 +1,24 @@
+use std::{
+    collections::VecDeque,
+    future::IntoFuture,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::Stream;
 use serde::Serialize;
 
 use crate::{
-    model::event::{EventSort, Events},
+    model::{
+        cursor::EventsCursor,
+        event::{Event, EventSort, EventType, Events},
+    },
     routing::Route,
-    Osu,
+    Osu, OsuResult,
 };
 
-use super::{Query, Request};
+use super::{PageFuture, Query, Request};
 
 /// Get [`Events`].
 #[must_use = "requests must be configured and executed"]
@@ -16,7 +28,7 @@ pub struct GetEvents<'a> {
     osu: &'a Osu,
     sort: Option<EventSort>,
     #[serde(rename = "cursor_string")]
-    cursor: Option<&'a str>,
+    cursor: Option<EventsCursor>,
 }
 
 impl<'a> GetEvents<'a> {
@@ -36,9 +48,12 @@ impl<'a> GetEvents<'a> {
         self
     }
 
-    /// Cursor for pagination
+    /// Continue from the given cursor, e.g. obtained through [`Events::cursor`].
+    ///
+    /// The sort order should be the same as for the request that provided
+    /// the cursor.
     #[inline]
-    pub const fn cursor(mut self, cursor: &'a str) -> Self {
+    pub fn cursor(mut self, cursor: EventsCursor) -> Self {
         self.cursor = Some(cursor);
 
         self
@@ -57,3 +72,197 @@ into_future! {
         Ok(events)
     }
 }
+
+type EventFilter<'a> = Box<dyn Fn(&EventType) -> bool + Send + 'a>;
+
+/// A [`Stream`] that polls the global activity feed and yields new [`Event`]s
+/// in chronological order.
+///
+/// Created through [`Osu::event_watcher`]. The first request is sent
+/// immediately and only determines the most recent event, unless the
+/// watcher was told to start [`after`](EventWatcher::after) a given event.
+/// Subsequent requests are sent every [`interval`](EventWatcher::interval)
+/// and follow the cursor until they reach a known event so no event is
+/// missed between polls.
+///
+/// Errors are yielded but do not end the stream; the stream never ends on
+/// its own.
+///
+/// ## Example
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// use rosu_v2::prelude::*;
+///
+/// # let _ = async {
+/// # let osu: Osu = unimplemented!();
+/// let mut watcher = osu
+///     .event_watcher()
+///     .filter(|event| matches!(event, EventType::Rank { rank: 1, .. }));
+///
+/// while let Some(event) = watcher.next().await {
+///     if let EventType::Rank { user, beatmap, .. } = event?.event_type {
+///         println!("{} got #1 on {}", user.username, beatmap.title);
+///     }
+/// }
+/// # Ok::<_, OsuError>(()) };
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct EventWatcher<'a> {
+    osu: &'a Osu,
+    interval: Duration,
+    filter: Option<EventFilter<'a>>,
+    last_event_id: Option<u32>,
+    events: VecDeque<Event>,
+    next: Option<PageFuture<'a, Vec<Event>>>,
+    requested: bool,
+}
+
+impl<'a> EventWatcher<'a> {
+    const DEFAULT_INTERVAL: Duration = Duration::from_secs(10);
+
+    pub(crate) const fn new(osu: &'a Osu) -> Self {
+        Self {
+            osu,
+            interval: Self::DEFAULT_INTERVAL,
+            filter: None,
+            last_event_id: None,
+            events: VecDeque::new(),
+            next: None,
+            requested: false,
+        }
+    }
+
+    /// Specify how long to wait between requests. Defaults to 10 seconds.
+    pub const fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+
+        self
+    }
+
+    /// Only yield events after the given event id, e.g. obtained through
+    /// [`EventWatcher::last_event_id`] before a restart.
+    pub const fn after(mut self, event_id: u32) -> Self {
+        self.last_event_id = Some(event_id);
+
+        self
+    }
+
+    /// Only yield events whose type matches the given predicate.
+    pub fn filter(mut self, filter: impl Fn(&EventType) -> bool + Send + 'a) -> Self {
+        self.filter = Some(Box::new(filter));
+
+        self
+    }
+
+    /// The id of the most recent event that was received, including events
+    /// that did not pass the filter.
+    pub const fn last_event_id(&self) -> Option<u32> {
+        self.last_event_id
+    }
+
+    fn next_request(&mut self) -> PageFuture<'a, Vec<Event>> {
+        let osu = self.osu;
+        let last_event_id = self.last_event_id;
+        let delay = self.requested.then_some(self.interval);
+        self.requested = true;
+
+        Box::pin(async move {
+            if let Some(delay) = delay {
+                tokio::time::sleep(delay).await;
+            }
+
+            let mut page = osu.events().sort(EventSort::IdDescending).await?;
+
+            let Some(last_event_id) = last_event_id else {
+                return Ok(page.events);
+            };
+
+            let mut events = Vec::new();
+
+            loop {
+                let reached = page
+                    .events
+                    .iter()
+                    .any(|event| event.event_id <= last_event_id);
+
+                events.extend(
+                    page.events
+                        .into_iter()
+                        .filter(|event| event.event_id > last_event_id),
+                );
+
+                match page.cursor {
+                    Some(cursor) if !reached => {
+                        page = osu
+                            .events()
+                            .sort(EventSort::IdDescending)
+                            .cursor(cursor)
+                            .into_future()
+                            .await?;
+                    }
+                    _ => break,
+                }
+            }
+
+            Ok(events)
+        })
+    }
+
+    fn handle_events(&mut self, mut events: Vec<Event>) {
+        events.sort_unstable_by_key(|event| event.event_id);
+        events.dedup_by_key(|event| event.event_id);
+
+        let Some(last) = events.last() else { return };
+
+        // Without a known event, the first request only sets the baseline
+        if self.last_event_id.replace(last.event_id).is_none() {
+            return;
+        }
+
+        let filter = self.filter.as_ref();
+
+        self.events.extend(
+            events
+                .into_iter()
+                .filter(|event| filter.is_none_or(|filter| filter(&event.event_type))),
+        );
+    }
+}
+
+impl Unpin for EventWatcher<'_> {}
+
+impl Stream for EventWatcher<'_> {
+    type Item = OsuResult<Event>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            if let Some(event) = this.events.pop_front() {
+                return Poll::Ready(Some(Ok(event)));
+            }
+
+            if this.next.is_none() {
+                this.next = Some(this.next_request());
+            }
+
+            let Some(next) = this.next.as_mut() else {
+                return Poll::Ready(None);
+            };
+
+            match next.as_mut().poll(cx) {
+                Poll::Ready(Ok(events)) => {
+                    this.next = None;
+                    this.handle_events(events);
+                }
+                Poll::Ready(Err(err)) => {
+                    this.next = None;
+
+                    return Poll::Ready(Some(Err(err)));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -1,12 +1,274 @@
 use crate::{
-    model::forum::ForumPosts,
-    request::{Query, Request},
+    model::{
+        cursor::{ForumPostsCursor, ForumTopicsCursor},
+        forum::{
+            CreatedForumTopic, Forum, ForumDetails, ForumPost, ForumPosts, ForumTopic,
+            ForumTopicPoll, ForumTopicSort, ForumTopics, ForumTopicsRequest,
+        },
+        DeserializedList,
+    },
+    request::{JsonBody, Query, Request},
     routing::Route,
     Osu,
 };
 
 use serde::Serialize;
 
+/// Get a vec of all [`Forum`]s including their subforums.
+#[must_use = "requests must be configured and executed"]
+pub struct GetForums<'a> {
+    osu: &'a Osu,
+}
+
+impl<'a> GetForums<'a> {
+    pub(crate) const fn new(osu: &'a Osu) -> Self {
+        Self { osu }
+    }
+}
+
+into_future! {
+    |self: GetForums<'_>| -> DeserializedList<Forum> {
+        Request::new(Route::GetForums)
+    } => |forums, _| -> Vec<Forum> {
+        Ok(forums.0)
+    }
+}
+
+/// Get [`ForumDetails`] containing a forum and its topics.
+#[must_use = "requests must be configured and executed"]
+pub struct GetForum<'a> {
+    osu: &'a Osu,
+    forum_id: u32,
+}
+
+impl<'a> GetForum<'a> {
+    pub(crate) const fn new(osu: &'a Osu, forum_id: u32) -> Self {
+        Self { osu, forum_id }
+    }
+}
+
+into_future! {
+    |self: GetForum<'_>| -> ForumDetails {
+        Request::new(Route::GetForum {
+            forum_id: self.forum_id,
+        })
+    }
+}
+
+/// Get [`ForumTopics`] across all forums or of a specific forum.
+#[must_use = "requests must be configured and executed"]
+#[derive(Serialize)]
+pub struct GetForumTopics<'a> {
+    #[serde(skip)]
+    osu: &'a Osu,
+    forum_id: Option<u32>,
+    sort: Option<ForumTopicSort>,
+    limit: Option<usize>,
+    #[serde(rename = "cursor_string")]
+    cursor: Option<ForumTopicsCursor>,
+}
+
+impl<'a> GetForumTopics<'a> {
+    pub(crate) const fn new(osu: &'a Osu) -> Self {
+        Self {
+            osu,
+            forum_id: None,
+            sort: None,
+            limit: None,
+            cursor: None,
+        }
+    }
+
+    /// Only get topics of the given forum.
+    #[inline]
+    pub const fn forum_id(mut self, forum_id: u32) -> Self {
+        self.forum_id = Some(forum_id);
+
+        self
+    }
+
+    /// Specify the sort order. Defaults to [`ForumTopicSort::New`].
+    #[inline]
+    pub const fn sort(mut self, sort: ForumTopicSort) -> Self {
+        self.sort = Some(sort);
+
+        self
+    }
+
+    /// Maximum number of topics to be returned (50 at most)
+    #[inline]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit.min(50));
+
+        self
+    }
+
+    /// Specify a page by providing a cursor, e.g. obtained through
+    /// [`ForumTopics::cursor`].
+    #[inline]
+    pub fn cursor(mut self, cursor: ForumTopicsCursor) -> Self {
+        self.cursor = Some(cursor);
+
+        self
+    }
+}
+
+into_future! {
+    |self: GetForumTopics<'_>| -> ForumTopics {
+        let params = (self.forum_id, self.sort, self.limit);
+
+        (Request::with_query(Route::GetForumTopics, Query::encode(&self)), params)
+    } => |topics, params: (Option<u32>, Option<ForumTopicSort>, Option<usize>)| -> ForumTopics {
+        let (forum_id, sort, limit) = params;
+
+        topics.request = Some(ForumTopicsRequest {
+            forum_id,
+            sort,
+            limit,
+        });
+
+        Ok(topics)
+    }
+}
+
+/// Get a [`ForumTopic`].
+#[must_use = "requests must be configured and executed"]
+pub struct GetForumTopic<'a> {
+    osu: &'a Osu,
+    topic_id: u64,
+}
+
+impl<'a> GetForumTopic<'a> {
+    pub(crate) const fn new(osu: &'a Osu, topic_id: u64) -> Self {
+        Self { osu, topic_id }
+    }
+}
+
+into_future! {
+    |self: GetForumTopic<'_>| -> ForumPosts {
+        // The topic is only available alongside its posts
+        Request::with_query(
+            Route::GetForumPosts { topic_id: self.topic_id },
+            "limit=1".to_owned(),
+        )
+    } => |posts, _| -> ForumTopic {
+        Ok(posts.topic)
+    }
+}
+
+/// Create a [`ForumTopic`], optionally with a poll.
+#[must_use = "requests must be configured and executed"]
+pub struct CreateForumTopic<'a> {
+    osu: &'a Osu,
+    forum_id: u32,
+    title: String,
+    body: String,
+    poll: Option<ForumTopicPoll>,
+}
+
+impl<'a> CreateForumTopic<'a> {
+    pub(crate) const fn new(osu: &'a Osu, forum_id: u32, title: String, body: String) -> Self {
+        Self {
+            osu,
+            forum_id,
+            title,
+            body,
+            poll: None,
+        }
+    }
+
+    /// Attach a poll to the topic.
+    #[inline]
+    pub fn poll(mut self, poll: ForumTopicPoll) -> Self {
+        self.poll = Some(poll);
+
+        self
+    }
+}
+
+into_future! {
+    |self: CreateForumTopic<'_>| -> CreatedForumTopic {
+        let mut body = JsonBody::new();
+        body.push_str("body", &self.body);
+        body.push_int("forum_id", self.forum_id);
+        body.push_str("title", &self.title);
+        body.push_bool("with_poll", self.poll.is_some());
+
+        if let Some(ref poll) = self.poll {
+            let mut poll_body = JsonBody::new();
+            poll_body.push_bool("hide_results", poll.hide_results);
+            poll_body.push_int("length_days", poll.length_days);
+            poll_body.push_int("max_options", poll.max_options);
+            // Options are expected to be separated by newlines
+            poll_body.push_str("options", &poll.options.join("\n"));
+            poll_body.push_str("title", &poll.title);
+            poll_body.push_bool("vote_change", poll.vote_change);
+
+            body.push_body("forum_topic_poll", poll_body);
+        }
+
+        Request::with_body(Route::PostForumTopic, body)
+    }
+}
+
+/// Reply to a [`ForumTopic`] by creating a [`ForumPost`].
+#[must_use = "requests must be configured and executed"]
+pub struct ReplyForumTopic<'a> {
+    osu: &'a Osu,
+    topic_id: u64,
+    body: String,
+}
+
+impl<'a> ReplyForumTopic<'a> {
+    pub(crate) const fn new(osu: &'a Osu, topic_id: u64, body: String) -> Self {
+        Self {
+            osu,
+            topic_id,
+            body,
+        }
+    }
+}
+
+into_future! {
+    |self: ReplyForumTopic<'_>| -> ForumPost {
+        let route = Route::PostForumTopicReply {
+            topic_id: self.topic_id,
+        };
+
+        let mut body = JsonBody::new();
+        body.push_str("body", &self.body);
+
+        Request::with_body(route, body)
+    }
+}
+
+/// Edit the content of a [`ForumPost`].
+#[must_use = "requests must be configured and executed"]
+pub struct EditForumPost<'a> {
+    osu: &'a Osu,
+    post_id: u64,
+    body: String,
+}
+
+impl<'a> EditForumPost<'a> {
+    pub(crate) const fn new(osu: &'a Osu, post_id: u64, body: String) -> Self {
+        Self { osu, post_id, body }
+    }
+}
+
+into_future! {
+    |self: EditForumPost<'_>| -> ForumPost {
+        let route = Route::PutForumPost {
+            post_id: self.post_id,
+        };
+
+        let mut body = JsonBody::new();
+        body.push_str("body", &self.body);
+
+        Request::with_body(route, body)
+    }
+}
+
 /// Get a [`ForumPosts`] struct for a forum topic
 #[must_use = "requests must be configured and executed"]
 #[derive(Serialize)]
@@ -20,7 +282,7 @@ pub struct GetForumPosts<'a> {
     start: Option<u64>,
     end: Option<u64>,
     #[serde(rename = "cursor_string")]
-    cursor: Option<&'a str>,
+    cursor: Option<ForumPostsCursor>,
 }
 
 impl<'a> GetForumPosts<'a> {
@@ -78,9 +340,10 @@ impl<'a> GetForumPosts<'a> {
         self
     }
 
-    /// Specify a page by providing a cursor
+    /// Specify a page by providing a cursor, e.g. obtained through
+    /// [`ForumPosts::cursor`].
     #[inline]
-    pub const fn cursor(mut self, cursor: &'a str) -> Self {
+    pub fn cursor(mut self, cursor: ForumPostsCursor) -> Self {
         self.cursor = Some(cursor);
 
         self
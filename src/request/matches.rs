@@ -1,10 +1,23 @@
+use std::{
+    collections::VecDeque,
+    future::{Future, IntoFuture},
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
 use crate::{
-    model::matches::{MatchList, OsuMatch},
-    request::{Query, Request},
+    error::OsuError,
+    model::{
+        cursor::MatchesCursor,
+        matches::{MatchEvent, MatchList, MatchUpdate, OsuMatch},
+    },
+    request::{PageFuture, Query, Request},
     routing::Route,
-    Osu,
+    Osu, OsuResult,
 };
 
+use futures::Stream;
 use serde::Serialize;
 
 /// Get an [`OsuMatch`].
@@ -77,7 +90,7 @@ pub struct GetMatches<'a> {
     #[serde(skip)]
     osu: &'a Osu,
     #[serde(rename = "cursor_string")]
-    cursor: Option<&'a str>,
+    cursor: Option<MatchesCursor>,
 }
 
 impl<'a> GetMatches<'a> {
@@ -85,8 +98,9 @@ impl<'a> GetMatches<'a> {
         Self { osu, cursor: None }
     }
 
+    /// Continue from the given cursor, e.g. obtained through [`MatchList::cursor`].
     #[inline]
-    pub(crate) const fn cursor(mut self, cursor: &'a str) -> Self {
+    pub fn cursor(mut self, cursor: MatchesCursor) -> Self {
         self.cursor = Some(cursor);
 
         self
@@ -101,3 +115,247 @@ into_future! {
         )
     }
 }
+
+/// Get an [`OsuMatch`] containing all of its events.
+#[must_use = "requests must be configured and executed"]
+pub struct GetMatchFull<'a> {
+    osu: &'a Osu,
+    match_id: u32,
+}
+
+impl<'a> GetMatchFull<'a> {
+    pub(crate) const fn new(osu: &'a Osu, match_id: u32) -> Self {
+        Self { osu, match_id }
+    }
+}
+
+impl<'a> IntoFuture for GetMatchFull<'a> {
+    type Output = OsuResult<OsuMatch>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let Self { osu, match_id } = self;
+
+        Box::pin(async move {
+            let mut full = osu.osu_match(match_id).limit(100).await?;
+
+            // Walk backwards until the first event is contained
+            while let Some(first_id) = full
+                .events
+                .first()
+                .map(MatchEvent::event_id)
+                .filter(|&first_id| first_id > full.first_event_id)
+            {
+                let previous = osu.osu_match(match_id).before(first_id).limit(100).await?;
+
+                if full.merge(previous) == 0 {
+                    break;
+                }
+            }
+
+            // Walk forwards in case events happened in the meanwhile
+            while let Some(last_id) = full
+                .events
+                .last()
+                .map(MatchEvent::event_id)
+                .filter(|&last_id| last_id < full.latest_event_id)
+            {
+                let next = osu.osu_match(match_id).after(last_id).limit(100).await?;
+
+                if full.merge(next) == 0 {
+                    break;
+                }
+            }
+
+            Ok(full)
+        })
+    }
+}
+
+/// A [`Stream`] that polls a live [`OsuMatch`] and yields its changes as
+/// [`MatchUpdate`]s.
+///
+/// Created through [`OsuMatch::watch`]. Requests are sent every
+/// [`interval`](MatchWatcher::interval), starting one interval after the
+/// watcher was first polled. Errors are yielded but do not end the stream
+/// unless the match could not be found anymore; it also ends after the match
+/// was disbanded or has an end time.
+///
+/// ## Example
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// use rosu_v2::prelude::*;
+///
+/// # let _ = async {
+/// # let osu: Osu = unimplemented!();
+/// let mut watcher = osu.osu_match(123).await?.watch(&osu);
+///
+/// while let Some(update) = watcher.next().await {
+///     if let MatchUpdate::GameFinished { game } = update? {
+///         println!("Game finished with {} scores", game.scores.len());
+///     }
+/// }
+/// # Ok::<_, OsuError>(()) };
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct MatchWatcher<'a> {
+    osu: &'a Osu,
+    state: OsuMatch,
+    interval: Duration,
+    updates: VecDeque<MatchUpdate>,
+    next: Option<PageFuture<'a, OsuMatch>>,
+    done: bool,
+}
+
+impl<'a> MatchWatcher<'a> {
+    const DEFAULT_INTERVAL: Duration = Duration::from_secs(10);
+
+    pub(crate) fn new(osu: &'a Osu, state: OsuMatch) -> Self {
+        let done = Self::is_over(&state);
+
+        Self {
+            osu,
+            state,
+            interval: Self::DEFAULT_INTERVAL,
+            updates: VecDeque::new(),
+            next: None,
+            done,
+        }
+    }
+
+    /// Specify how long to wait between requests. Defaults to 10 seconds.
+    pub const fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+
+        self
+    }
+
+    /// The current state of the match, containing all events received so far.
+    pub const fn osu_match(&self) -> &OsuMatch {
+        &self.state
+    }
+
+    /// Consume the watcher and return the current state of the match.
+    pub fn into_match(self) -> OsuMatch {
+        self.state
+    }
+
+    fn is_over(state: &OsuMatch) -> bool {
+        state.end_time.is_some()
+            || matches!(state.events.last(), Some(MatchEvent::Disbanded { .. }))
+    }
+
+    fn next_request(&self) -> PageFuture<'a, OsuMatch> {
+        let osu = self.osu;
+        let match_id = self.state.match_id;
+        let after = self.state.resume_after();
+        let interval = self.interval;
+
+        Box::pin(async move {
+            tokio::time::sleep(interval).await;
+
+            osu.osu_match(match_id).after(after).limit(100).await
+        })
+    }
+
+    fn merge(&mut self, chunk: OsuMatch) {
+        for event in &chunk.events {
+            let event_id = event.event_id();
+
+            let known = self
+                .state
+                .events
+                .iter()
+                .rfind(|known| known.event_id() == event_id);
+
+            if let Some(known) = known {
+                // Only games change after their event was created
+                if let (MatchEvent::Game { game: prev, .. }, MatchEvent::Game { game, .. }) =
+                    (known, event)
+                {
+                    if prev.end_time.is_none() && game.end_time.is_some() {
+                        self.updates
+                            .push_back(MatchUpdate::GameFinished { game: game.clone() });
+                    }
+                }
+
+                continue;
+            }
+
+            match event {
+                MatchEvent::Create { .. } => {}
+                MatchEvent::Disbanded { .. } => self.updates.push_back(MatchUpdate::Disbanded),
+                MatchEvent::Game { game, .. } => {
+                    self.updates
+                        .push_back(MatchUpdate::GameStarted { game: game.clone() });
+
+                    if game.end_time.is_some() {
+                        self.updates
+                            .push_back(MatchUpdate::GameFinished { game: game.clone() });
+                    }
+                }
+                MatchEvent::HostChanged { user_id, .. } => self
+                    .updates
+                    .push_back(MatchUpdate::HostChanged { user_id: *user_id }),
+                MatchEvent::Joined { user_id, .. } => self
+                    .updates
+                    .push_back(MatchUpdate::Joined { user_id: *user_id }),
+                MatchEvent::Kicked { user_id, .. } => self
+                    .updates
+                    .push_back(MatchUpdate::Kicked { user_id: *user_id }),
+                MatchEvent::Left { user_id, .. } => self
+                    .updates
+                    .push_back(MatchUpdate::Left { user_id: *user_id }),
+            }
+        }
+
+        self.state.merge(chunk);
+        self.done = Self::is_over(&self.state);
+    }
+}
+
+impl Unpin for MatchWatcher<'_> {}
+
+impl Stream for MatchWatcher<'_> {
+    type Item = OsuResult<MatchUpdate>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            if let Some(update) = this.updates.pop_front() {
+                return Poll::Ready(Some(Ok(update)));
+            }
+
+            if this.done {
+                return Poll::Ready(None);
+            }
+
+            if this.next.is_none() {
+                this.next = Some(this.next_request());
+            }
+
+            let Some(next) = this.next.as_mut() else {
+                return Poll::Ready(None);
+            };
+
+            match next.as_mut().poll(cx) {
+                Poll::Ready(Ok(chunk)) => {
+                    this.next = None;
+                    this.merge(chunk);
+                }
+                Poll::Ready(Err(err)) => {
+                    this.next = None;
+
+                    // The match won't reappear so there is nothing to watch
+                    // anymore
+                    this.done = matches!(err, OsuError::NotFound);
+
+                    return Poll::Ready(Some(Err(err)));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -59,6 +59,7 @@ macro_rules! into_future {
         )?
     ) => {
         #[doc(hidden)]
+        #[derive(Clone)]
         pub struct $from_user_data {
             $( $data_field_name: $data_field_ty, )*
         }
@@ -139,18 +140,24 @@ use crate::routing::Route;
 pub use crate::future::OsuFuture;
 
 pub use self::{
-    beatmap::*, comments::*, event::*, forum::*, matches::*, news::*, ranking::*, replay::*,
-    score::*, seasonal_backgrounds::*, user::*, wiki::*,
+    batch::*, beatmap::*, bulk::BulkFuture, chat::*, comments::*, event::*, forum::*, matches::*,
+    news::*, paginator::*, ranking::*, replay::*, room::*, score::*, seasonal_backgrounds::*,
+    user::*, wiki::*,
 };
 
+mod batch;
 mod beatmap;
+mod bulk;
+mod chat;
 mod comments;
 mod event;
 mod forum;
 mod matches;
 mod news;
+mod paginator;
 mod ranking;
 mod replay;
+mod room;
 mod score;
 mod seasonal_backgrounds;
 mod serialize;
@@ -161,6 +168,8 @@ mod wiki;
 pub(crate) enum Method {
     Get,
     Post,
+    Put,
+    Delete,
 }
 
 impl Method {
@@ -168,6 +177,8 @@ impl Method {
         match self {
             Method::Get => hyper::Method::GET,
             Method::Post => hyper::Method::POST,
+            Method::Put => hyper::Method::PUT,
+            Method::Delete => hyper::Method::DELETE,
         }
     }
 }
@@ -242,10 +253,17 @@ impl JsonBody {
     }
 
     pub(crate) fn push_str(&mut self, key: &str, value: &str) {
-        self.inner.reserve(4 + key.len() + 2 + value.len());
+        self.push_key(key.as_bytes());
+        serde_json::to_writer(&mut self.inner, value).expect("serde_json should not fail");
+    }
+
+    pub(crate) fn push_bool(&mut self, key: &str, value: bool) {
+        let value: &[u8] = if value { b"true" } else { b"false" };
+
+        self.inner.reserve(4 + key.len() + value.len());
 
         self.push_key(key.as_bytes());
-        self.push_value(value.as_bytes());
+        self.inner.extend_from_slice(value);
     }
 
     pub(crate) fn push_int(&mut self, key: &str, int: impl Integer) {
@@ -258,6 +276,20 @@ impl JsonBody {
         self.push_value(int.as_bytes());
     }
 
+    pub(crate) fn push_body(&mut self, key: &str, body: Self) {
+        let body = body.into_bytes();
+
+        self.inner.reserve(4 + key.len() + body.len().max(2));
+
+        self.push_key(key.as_bytes());
+
+        if body.is_empty() {
+            self.inner.extend_from_slice(b"{}");
+        } else {
+            self.inner.extend_from_slice(&body);
+        }
+    }
+
     pub(crate) fn into_bytes(mut self) -> Vec<u8> {
         if !self.inner.is_empty() {
             self.inner.push(b'}');
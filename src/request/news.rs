@@ -1,5 +1,5 @@
 use crate::{
-    model::news::News,
+    model::{cursor::NewsCursor, news::News},
     request::{Query, Request},
     routing::Route,
     Osu,
@@ -15,7 +15,7 @@ pub struct GetNews<'a> {
     osu: &'a Osu,
     news: Option<()>, // TODO
     #[serde(rename = "cursor_string")]
-    cursor: Option<&'a str>,
+    cursor: Option<NewsCursor>,
 }
 
 impl<'a> GetNews<'a> {
@@ -27,8 +27,9 @@ impl<'a> GetNews<'a> {
         }
     }
 
+    /// Continue from the given cursor, e.g. obtained through [`News::cursor`].
     #[inline]
-    pub(crate) const fn cursor(mut self, cursor: &'a str) -> Self {
+    pub fn cursor(mut self, cursor: NewsCursor) -> Self {
         self.cursor = Some(cursor);
 
         self
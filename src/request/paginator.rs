@@ -0,0 +1,366 @@
+use std::{
+    collections::HashSet,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    vec::IntoIter,
+};
+
+use futures::Stream;
+
+use crate::{Osu, OsuResult};
+
+/// Future that resolves to the next page of a [`Paginated`] response.
+pub type PageFuture<'a, P> = Pin<Box<dyn Future<Output = OsuResult<P>> + Send + 'a>>;
+
+/// A response that is one page of many.
+///
+/// Implemented by all cursor- and page-based responses so that they can be
+/// turned into a [`Paginator`] through their `into_stream` method.
+pub trait Paginated: Sized + Send {
+    /// The type of the items on each page.
+    type Item: Send;
+
+    /// Create a future that fetches the next page if there is one.
+    ///
+    /// The future does not borrow `self` and does not even create the request
+    /// until it's polled, i.e. until the items of the current page ran out.
+    fn next_page<'a>(&self, osu: &'a Osu) -> Option<PageFuture<'a, Self>>;
+
+    /// The items of the page.
+    fn items(&self) -> &[Self::Item];
+
+    /// Move the items out of the page.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// A [`Stream`] that lazily yields the items of a [`Paginated`] response and
+/// all subsequent pages.
+///
+/// The next page is only requested once all items of the current page were
+/// consumed so the stream never fetches more than it is asked for.
+///
+/// The stream ends once the osu!api has no further pages, a page is empty, a
+/// limit is reached, or after the first error.
+///
+/// Use [`Paginator::pages`] to yield whole pages instead of single items.
+///
+/// ## Example
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// use rosu_v2::prelude::*;
+///
+/// # let _ = async {
+/// # let osu: Osu = unimplemented!();
+/// let news = osu.news().await?;
+/// let mut posts = news.into_stream(&osu).max_items(100);
+///
+/// while let Some(post) = posts.next().await {
+///     println!("{}", post?.title);
+/// }
+/// # Ok::<_, OsuError>(()) };
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct Paginator<'a, P: Paginated> {
+    osu: &'a Osu,
+    first: Option<P>,
+    items: IntoIter<P::Item>,
+    next: Option<PageFuture<'a, P>>,
+    pages: usize,
+    max_pages: Option<usize>,
+    yielded: usize,
+    max_items: Option<usize>,
+}
+
+impl<'a, P: Paginated> Paginator<'a, P> {
+    /// Create a new [`Paginator`] starting at the given page.
+    pub fn new(osu: &'a Osu, page: P) -> Self {
+        Self {
+            osu,
+            first: Some(page),
+            items: Vec::new().into_iter(),
+            next: None,
+            pages: 0,
+            max_pages: None,
+            yielded: 0,
+            max_items: None,
+        }
+    }
+
+    /// Stop after this many pages, including the initial page.
+    pub const fn max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = Some(max_pages);
+
+        self
+    }
+
+    /// Stop after yielding this many items.
+    pub const fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+
+        self
+    }
+
+    /// Yield whole pages instead of single items.
+    ///
+    /// [`Paginator::max_items`] is ignored for pages. The initial page is
+    /// always yielded, subsequent empty pages end the stream.
+    pub fn pages(self) -> Pages<'a, P> {
+        Pages {
+            osu: self.osu,
+            first: self.first,
+            next: self.next,
+            count: self.pages,
+            limit: self.max_pages,
+        }
+    }
+
+    fn handle_page(&mut self, page: P) {
+        self.pages += 1;
+
+        self.next =
+            if page.items().is_empty() || self.max_pages.is_some_and(|max| self.pages >= max) {
+                None
+            } else {
+                page.next_page(self.osu)
+            };
+
+        self.items = page.into_items().into_iter();
+    }
+}
+
+impl<P: Paginated> Unpin for Paginator<'_, P> {}
+
+impl<P: Paginated> Stream for Paginator<'_, P> {
+    type Item = OsuResult<P::Item>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            if this.max_items.is_some_and(|max| this.yielded >= max) {
+                return Poll::Ready(None);
+            }
+
+            if let Some(item) = this.items.next() {
+                this.yielded += 1;
+
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            if let Some(page) = this.first.take() {
+                this.handle_page(page);
+
+                continue;
+            }
+
+            let Some(next) = this.next.as_mut() else {
+                return Poll::Ready(None);
+            };
+
+            match next.as_mut().poll(cx) {
+                Poll::Ready(Ok(page)) => this.handle_page(page),
+                Poll::Ready(Err(err)) => {
+                    this.next = None;
+
+                    return Poll::Ready(Some(Err(err)));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let upper = self.max_items.map(|max| max.saturating_sub(self.yielded));
+
+        if self.next.is_none() && self.first.is_none() {
+            let len = self.items.len();
+
+            (len, Some(upper.map_or(len, |upper| upper.min(len))))
+        } else {
+            (self.items.len(), upper)
+        }
+    }
+}
+
+/// A [`Stream`] that lazily yields a [`Paginated`] response and all
+/// subsequent pages.
+///
+/// Created through [`Paginator::pages`].
+#[must_use = "streams do nothing unless polled"]
+pub struct Pages<'a, P: Paginated> {
+    osu: &'a Osu,
+    first: Option<P>,
+    next: Option<PageFuture<'a, P>>,
+    count: usize,
+    limit: Option<usize>,
+}
+
+impl<P: Paginated> Pages<'_, P> {
+    fn handle_page(&mut self, page: P) -> P {
+        self.count += 1;
+
+        self.next = if page.items().is_empty() || self.limit.is_some_and(|max| self.count >= max) {
+            None
+        } else {
+            page.next_page(self.osu)
+        };
+
+        page
+    }
+}
+
+impl<P: Paginated> Unpin for Pages<'_, P> {}
+
+impl<P: Paginated> Stream for Pages<'_, P> {
+    type Item = OsuResult<P>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        if this.limit.is_some_and(|max| this.count >= max) {
+            return Poll::Ready(None);
+        }
+
+        if let Some(page) = this.first.take() {
+            return Poll::Ready(Some(Ok(this.handle_page(page))));
+        }
+
+        let Some(next) = this.next.as_mut() else {
+            return Poll::Ready(None);
+        };
+
+        match next.as_mut().poll(cx) {
+            Poll::Ready(Ok(page)) => {
+                // Empty pages end the stream
+                if page.items().is_empty() {
+                    this.next = None;
+
+                    return Poll::Ready(None);
+                }
+
+                Poll::Ready(Some(Ok(this.handle_page(page))))
+            }
+            Poll::Ready(Err(err)) => {
+                this.next = None;
+
+                Poll::Ready(Some(Err(err)))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+type FetchItems<'a, T> = Box<dyn FnMut(usize, usize) -> PageFuture<'a, Vec<T>> + Send + 'a>;
+
+/// A [`Stream`] that lazily yields all items of an offset-paged endpoint.
+///
+/// Pages are requested starting at the request's offset. The request's limit,
+/// if specified, is used as page size but capped at a maximum per endpoint
+/// which is also used if no limit is specified. The next page is only
+/// requested once all items of the current page were consumed.
+///
+/// Since the osu!api may return fewer items than requested, the stream only
+/// ends once a page is empty, the osu!api's maximum offset is reached, or
+/// after the first error. Items that shifted into the next page while reading
+/// are only yielded once.
+///
+/// ## Example
+///
+/// ```no_run
+/// use futures::TryStreamExt;
+/// use rosu_v2::prelude::*;
+///
+/// # let _ = async {
+/// # let osu: Osu = unimplemented!();
+/// let top_scores: Vec<Score> = osu.user_scores(2).best().all().try_collect().await?;
+/// # Ok::<_, OsuError>(()) };
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct OffsetPaginator<'a, T> {
+    fetch: FetchItems<'a, T>,
+    key: fn(&T) -> u64,
+    seen: HashSet<u64>,
+    offset: usize,
+    page_size: usize,
+    max_offset: Option<usize>,
+    items: IntoIter<T>,
+    next: Option<PageFuture<'a, Vec<T>>>,
+    done: bool,
+}
+
+impl<'a, T> OffsetPaginator<'a, T> {
+    pub(crate) fn new(
+        fetch: impl FnMut(usize, usize) -> PageFuture<'a, Vec<T>> + Send + 'a,
+        key: fn(&T) -> u64,
+        offset: Option<usize>,
+        limit: Option<usize>,
+        max_limit: usize,
+        max_offset: Option<usize>,
+    ) -> Self {
+        Self {
+            fetch: Box::new(fetch),
+            key,
+            seen: HashSet::new(),
+            offset: offset.unwrap_or(0),
+            page_size: limit.map_or(max_limit, |limit| limit.clamp(1, max_limit)),
+            max_offset,
+            items: Vec::new().into_iter(),
+            next: None,
+            done: false,
+        }
+    }
+}
+
+impl<T> Unpin for OffsetPaginator<'_, T> {}
+
+impl<T> Stream for OffsetPaginator<'_, T> {
+    type Item = OsuResult<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            for item in this.items.by_ref() {
+                if this.seen.insert((this.key)(&item)) {
+                    return Poll::Ready(Some(Ok(item)));
+                }
+            }
+
+            if this.done {
+                return Poll::Ready(None);
+            }
+
+            if this.next.is_none() {
+                let limit = match this.max_offset {
+                    Some(max) if this.offset >= max => return Poll::Ready(None),
+                    Some(max) => this.page_size.min(max - this.offset),
+                    None => this.page_size,
+                };
+
+                this.next = Some((this.fetch)(this.offset, limit));
+            }
+
+            let Some(next) = this.next.as_mut() else {
+                return Poll::Ready(None);
+            };
+
+            match next.as_mut().poll(cx) {
+                Poll::Ready(Ok(items)) => {
+                    this.done = items.is_empty();
+                    this.offset += items.len();
+                    this.items = items.into_iter();
+                    this.next = None;
+                }
+                Poll::Ready(Err(err)) => {
+                    this.done = true;
+                    this.next = None;
+
+                    return Poll::Ready(Some(Err(err)));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -100,13 +100,19 @@ impl<'a> GetCountryRankings<'a> {
 
 into_future! {
     |self: GetCountryRankings<'_>| -> CountryRankings {
-        Request::with_query(
+        let req = Request::with_query(
             Route::GetRankings {
                 mode: self.mode,
                 ranking_type: RankingType::Country,
             },
             Query::encode(&self),
-        )
+        );
+
+        (req, self.mode)
+    } => |rankings, mode: GameMode| -> CountryRankings {
+        rankings.mode = Some(mode);
+
+        Ok(rankings)
     }
 }
 
@@ -169,6 +175,18 @@ impl<'a> GetPerformanceRankings<'a> {
 
         self
     }
+
+    /// Apply the filters of previously fetched [`Rankings`].
+    pub(crate) fn filters(
+        mut self,
+        country: Option<CountryCode>,
+        variant: Option<&'static str>,
+    ) -> Self {
+        self.country = country;
+        self.variant = variant;
+
+        self
+    }
 }
 
 into_future! {
@@ -181,10 +199,13 @@ into_future! {
             Query::encode(&self),
         );
 
-        (req, self.mode)
-    } => |rankings, mode: GameMode| -> Rankings {
+        (req, (self.mode, self.country, self.variant))
+    } => |rankings, data: (GameMode, Option<CountryCode>, Option<&'static str>)| -> Rankings {
+        let (mode, country, variant) = data;
         rankings.mode = Some(mode);
         rankings.ranking_type = Some(RankingType::Performance);
+        rankings.country = country;
+        rankings.variant = variant;
 
         Ok(rankings)
     }
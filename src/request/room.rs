@@ -0,0 +1,227 @@
+use serde::Serialize;
+
+use crate::{
+    model::{
+        cursor::PlaylistScoresCursor,
+        room::{
+            PlaylistScores, PlaylistScoresRequest, PlaylistScoresSort, Room, RoomFilter,
+            RoomLeaderboard, RoomSort, RoomTypeGroup,
+        },
+    },
+    routing::Route,
+    Osu,
+};
+
+use super::{Query, Request};
+
+/// Get a vec of multiplayer [`Room`]s.
+#[must_use = "requests must be configured and executed"]
+#[derive(Serialize)]
+pub struct GetRooms<'a> {
+    #[serde(skip)]
+    osu: &'a Osu,
+    mode: Option<RoomFilter>,
+    season_id: Option<u32>,
+    sort: Option<RoomSort>,
+    type_group: Option<RoomTypeGroup>,
+    limit: Option<usize>,
+}
+
+impl<'a> GetRooms<'a> {
+    pub(crate) const fn new(osu: &'a Osu) -> Self {
+        Self {
+            osu,
+            mode: None,
+            season_id: None,
+            sort: None,
+            type_group: None,
+            limit: None,
+        }
+    }
+
+    /// Specify which rooms to get. Defaults to [`RoomFilter::Active`].
+    #[inline]
+    pub const fn filter(mut self, filter: RoomFilter) -> Self {
+        self.mode = Some(filter);
+
+        self
+    }
+
+    /// Only get rooms of the given playlists season.
+    #[inline]
+    pub const fn season_id(mut self, season_id: u32) -> Self {
+        self.season_id = Some(season_id);
+
+        self
+    }
+
+    /// Specify the sort order. Defaults to [`RoomSort::Created`].
+    #[inline]
+    pub const fn sort(mut self, sort: RoomSort) -> Self {
+        self.sort = Some(sort);
+
+        self
+    }
+
+    /// Specify whether to get playlists or realtime rooms. Defaults to
+    /// [`RoomTypeGroup::Playlists`].
+    #[inline]
+    pub const fn type_group(mut self, type_group: RoomTypeGroup) -> Self {
+        self.type_group = Some(type_group);
+
+        self
+    }
+
+    /// Limit the amount of rooms.
+    #[inline]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+
+        self
+    }
+}
+
+into_future! {
+    |self: GetRooms<'_>| -> Vec<Room> {
+        Request::with_query(Route::GetRooms, Query::encode(&self))
+    }
+}
+
+/// Get a multiplayer [`Room`] including its playlist.
+#[must_use = "requests must be configured and executed"]
+pub struct GetRoom<'a> {
+    osu: &'a Osu,
+    room_id: u64,
+}
+
+impl<'a> GetRoom<'a> {
+    pub(crate) const fn new(osu: &'a Osu, room_id: u64) -> Self {
+        Self { osu, room_id }
+    }
+}
+
+into_future! {
+    |self: GetRoom<'_>| -> Room {
+        Request::new(Route::GetRoom {
+            room_id: self.room_id,
+        })
+    }
+}
+
+/// Get the [`RoomLeaderboard`] of a multiplayer room.
+#[must_use = "requests must be configured and executed"]
+#[derive(Serialize)]
+pub struct GetRoomLeaderboard<'a> {
+    #[serde(skip)]
+    osu: &'a Osu,
+    #[serde(skip)]
+    room_id: u64,
+    limit: Option<usize>,
+}
+
+impl<'a> GetRoomLeaderboard<'a> {
+    pub(crate) const fn new(osu: &'a Osu, room_id: u64) -> Self {
+        Self {
+            osu,
+            room_id,
+            limit: None,
+        }
+    }
+
+    /// Limit the amount of entries, between 1 and 50. Defaults to 50.
+    #[inline]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+
+        self
+    }
+}
+
+into_future! {
+    |self: GetRoomLeaderboard<'_>| -> RoomLeaderboard {
+        let route = Route::GetRoomLeaderboard {
+            room_id: self.room_id,
+        };
+
+        Request::with_query(route, Query::encode(&self))
+    }
+}
+
+/// Get [`PlaylistScores`] of a playlist item.
+#[must_use = "requests must be configured and executed"]
+#[derive(Serialize)]
+pub struct GetPlaylistScores<'a> {
+    #[serde(skip)]
+    osu: &'a Osu,
+    #[serde(skip)]
+    room_id: u64,
+    #[serde(skip)]
+    playlist_item_id: u64,
+    limit: Option<usize>,
+    sort: Option<PlaylistScoresSort>,
+    #[serde(rename = "cursor_string")]
+    cursor: Option<PlaylistScoresCursor>,
+}
+
+impl<'a> GetPlaylistScores<'a> {
+    pub(crate) const fn new(osu: &'a Osu, room_id: u64, playlist_item_id: u64) -> Self {
+        Self {
+            osu,
+            room_id,
+            playlist_item_id,
+            limit: None,
+            sort: None,
+            cursor: None,
+        }
+    }
+
+    /// Limit the amount of scores per page, between 1 and 50. Defaults to 50.
+    #[inline]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+
+        self
+    }
+
+    /// Specify the sort order. Defaults to
+    /// [`PlaylistScoresSort::ScoreDescending`].
+    #[inline]
+    pub const fn sort(mut self, sort: PlaylistScoresSort) -> Self {
+        self.sort = Some(sort);
+
+        self
+    }
+
+    /// Continue from the given cursor, e.g. obtained through
+    /// [`PlaylistScores::cursor`].
+    #[inline]
+    pub fn cursor(mut self, cursor: PlaylistScoresCursor) -> Self {
+        self.cursor = Some(cursor);
+
+        self
+    }
+}
+
+into_future! {
+    |self: GetPlaylistScores<'_>| -> PlaylistScores {
+        let route = Route::GetPlaylistScores {
+            room_id: self.room_id,
+            playlist_item_id: self.playlist_item_id,
+        };
+
+        let params = (self.room_id, self.playlist_item_id, self.limit, self.sort);
+
+        (Request::with_query(route, Query::encode(&self)), params)
+    } => |scores, params: (u64, u64, Option<usize>, Option<PlaylistScoresSort>)| -> PlaylistScores {
+        let (room_id, playlist_item_id, limit, sort) = params;
+
+        scores.request = Some(PlaylistScoresRequest {
+            room_id,
+            playlist_item_id,
+            limit,
+            sort,
+        });
+
+        Ok(scores)
+    }
+}
@@ -1,13 +1,20 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::Stream;
 use rosu_mods::GameMode;
 use serde::Serialize;
 
 use crate::{
-    prelude::{ProcessedScores, Score},
+    prelude::{ProcessedScores, Score, ScoreFeedBatch, ScoreFeedCheckpoint, ScoresCursor},
     routing::Route,
-    Osu,
+    Osu, OsuResult,
 };
 
-use super::{serialize::maybe_mode_as_str, Query, Request};
+use super::{serialize::maybe_mode_as_str, PageFuture, Query, Request};
 
 /// Get a [`Score`] struct.
 #[must_use = "requests must be configured and executed"]
@@ -76,7 +83,7 @@ pub struct GetScores<'a> {
     #[serde(rename(serialize = "cursor[id]"))]
     score_id: Option<u64>,
     #[serde(rename(serialize = "cursor_string"))]
-    cursor: Option<Box<str>>,
+    cursor: Option<ScoresCursor>,
 }
 
 impl<'a> GetScores<'a> {
@@ -103,8 +110,9 @@ impl<'a> GetScores<'a> {
         self
     }
 
-    /// Specify a cursor
-    pub fn cursor(mut self, cursor: Box<str>) -> Self {
+    /// Continue from the given cursor, e.g. obtained through
+    /// [`ProcessedScores::cursor`].
+    pub fn cursor(mut self, cursor: ScoresCursor) -> Self {
         self.cursor = Some(cursor);
 
         self
@@ -120,3 +128,180 @@ into_future! {
         Ok(scores)
     }
 }
+
+/// A [`Stream`] that continuously polls recently processed scores and yields
+/// each [`Score`] exactly once.
+///
+/// Created through [`Osu::score_feed`]. New scores are yielded in batches,
+/// each carrying a [`ScoreFeedCheckpoint`]. Persist the checkpoint once a
+/// batch was processed and pass it to [`ScoreFeed::resume`] after a restart
+/// to continue without gaps or duplicates.
+///
+/// The first request is sent immediately, subsequent ones every
+/// [`interval`](ScoreFeed::interval). Polls without new scores yield nothing.
+/// Errors are yielded but do not end the stream; the stream never ends on
+/// its own.
+///
+/// ## Example
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// use rosu_v2::prelude::*;
+///
+/// # let _ = async {
+/// # let osu: Osu = unimplemented!();
+/// # let stored: Option<ScoreFeedCheckpoint> = None;
+/// let mut feed = osu.score_feed().mode(GameMode::Osu);
+///
+/// if let Some(checkpoint) = stored {
+///     feed = feed.resume(checkpoint);
+/// }
+///
+/// while let Some(batch) = feed.next().await {
+///     let batch = batch?;
+///
+///     for score in batch.scores {
+///         println!("{}: {}pp", score.id, score.pp.unwrap_or(0.0));
+///     }
+///
+///     // persist `batch.checkpoint`
+/// }
+/// # Ok::<_, OsuError>(()) };
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct ScoreFeed<'a> {
+    osu: &'a Osu,
+    mode: Option<GameMode>,
+    interval: Duration,
+    checkpoint: Option<ScoreFeedCheckpoint>,
+    next: Option<PageFuture<'a, ProcessedScores>>,
+    requested: bool,
+}
+
+impl<'a> ScoreFeed<'a> {
+    const DEFAULT_INTERVAL: Duration = Duration::from_secs(10);
+
+    pub(crate) const fn new(osu: &'a Osu) -> Self {
+        Self {
+            osu,
+            mode: None,
+            interval: Self::DEFAULT_INTERVAL,
+            checkpoint: None,
+            next: None,
+            requested: false,
+        }
+    }
+
+    /// Only yield scores of the given mode.
+    ///
+    /// Checkpoints are only valid for the mode they were created with.
+    pub const fn mode(mut self, mode: GameMode) -> Self {
+        self.mode = Some(mode);
+
+        self
+    }
+
+    /// Specify how long to wait between requests. Defaults to 10 seconds.
+    pub const fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+
+        self
+    }
+
+    /// Continue after the given checkpoint, e.g. obtained through
+    /// [`ScoreFeedBatch::checkpoint`].
+    pub fn resume(mut self, checkpoint: ScoreFeedCheckpoint) -> Self {
+        self.checkpoint = Some(checkpoint);
+
+        self
+    }
+
+    /// The checkpoint of the most recent batch.
+    pub const fn checkpoint(&self) -> Option<&ScoreFeedCheckpoint> {
+        self.checkpoint.as_ref()
+    }
+
+    fn next_request(&mut self) -> PageFuture<'a, ProcessedScores> {
+        let mut req = self.osu.scores();
+
+        if let Some(mode) = self.mode {
+            req = req.mode(mode);
+        }
+
+        if let Some(ref checkpoint) = self.checkpoint {
+            req = req.cursor(checkpoint.cursor().clone());
+        }
+
+        let delay = self.requested.then_some(self.interval);
+        self.requested = true;
+
+        Box::pin(async move {
+            if let Some(delay) = delay {
+                tokio::time::sleep(delay).await;
+            }
+
+            req.await
+        })
+    }
+
+    fn handle_scores(&mut self, scores: ProcessedScores) -> Option<ScoreFeedBatch> {
+        let last_score_id = self
+            .checkpoint
+            .as_ref()
+            .map_or(0, ScoreFeedCheckpoint::last_score_id);
+
+        let ProcessedScores { scores, cursor, .. } = scores;
+
+        let scores: Vec<_> = scores
+            .into_iter()
+            .filter(|score| score.id > last_score_id)
+            .collect();
+
+        let last_score_id = scores
+            .iter()
+            .map(|score| score.id)
+            .max()
+            .unwrap_or(last_score_id);
+
+        let checkpoint = ScoreFeedCheckpoint::new(cursor, last_score_id);
+        self.checkpoint = Some(checkpoint.clone());
+
+        (!scores.is_empty()).then_some(ScoreFeedBatch { scores, checkpoint })
+    }
+}
+
+impl Unpin for ScoreFeed<'_> {}
+
+impl Stream for ScoreFeed<'_> {
+    type Item = OsuResult<ScoreFeedBatch>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            if this.next.is_none() {
+                this.next = Some(this.next_request());
+            }
+
+            let Some(next) = this.next.as_mut() else {
+                return Poll::Ready(None);
+            };
+
+            match next.as_mut().poll(cx) {
+                Poll::Ready(Ok(scores)) => {
+                    this.next = None;
+
+                    if let Some(batch) = this.handle_scores(scores) {
+                        return Poll::Ready(Some(Ok(batch)));
+                    }
+                }
+                Poll::Ready(Err(err)) => {
+                    this.next = None;
+
+                    return Poll::Ready(Some(Err(err)));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
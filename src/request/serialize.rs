@@ -1,8 +1,9 @@
+use crate::model::beatmap::{BeatmapsetDiscussionMessageType, BeatmapsetPostType};
 use crate::model::GameMode;
 use crate::prelude::{CommentSort, GameModsIntermode};
 
 use crate::request::UserId;
-use serde::ser::{SerializeMap, Serializer};
+use serde::ser::{Serialize, SerializeMap, Serializer};
 use std::cmp;
 
 #[allow(clippy::ref_option)]
@@ -58,6 +59,36 @@ pub(crate) fn mods_as_list<S: Serializer>(
     map.end()
 }
 
+fn list<T: Serialize, S: Serializer>(
+    key: &str,
+    list: &[T],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(list.len()))?;
+
+    for elem in list {
+        map.serialize_entry(key, elem)?;
+    }
+
+    map.end()
+}
+
+#[allow(clippy::ptr_arg)]
+pub(crate) fn message_types_as_list<S: Serializer>(
+    message_types: &Vec<BeatmapsetDiscussionMessageType>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    list("message_types[]", message_types, serializer)
+}
+
+#[allow(clippy::ptr_arg)]
+pub(crate) fn post_types_as_list<S: Serializer>(
+    types: &Vec<BeatmapsetPostType>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    list("types[]", types, serializer)
+}
+
 #[allow(clippy::ref_option, clippy::trivially_copy_pass_by_ref)]
 pub(crate) fn maybe_comment_sort<S: Serializer>(
     sort: &Option<CommentSort>,
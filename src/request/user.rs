@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{fmt, future::IntoFuture};
 
 use itoa::Buffer;
 use serde::Serialize;
@@ -11,14 +11,16 @@ use crate::{
         kudosu::KudosuHistory,
         score::Score,
         user::{User, UserBeatmapsetsKind, UserExtended, Username},
-        DeserializedList, GameMode,
+        BulkLookup, DeserializedList, GameMode,
     },
     request::{
+        bulk::{self, BulkFuture},
+        paginator::{OffsetPaginator, PageFuture},
         serialize::{maybe_bool_as_u8, maybe_mode_as_str, user_id_type},
         Query, Request,
     },
     routing::Route,
-    Osu,
+    Osu, OsuResult,
 };
 
 /// Either a user id as `u32` or a username as [`Username`].
@@ -187,7 +189,7 @@ into_future! {
 
 /// Get the [`BeatmapsetExtended`]s of a user.
 #[must_use = "requests must be configured and executed"]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
 pub struct GetUserBeatmapsets<'a> {
     #[serde(skip)]
     osu: &'a Osu,
@@ -234,6 +236,25 @@ impl<'a> GetUserBeatmapsets<'a> {
 
         self
     }
+
+    /// Fetch all mapsets of the specified type by paging through offsets.
+    /// See [`OffsetPaginator`] for more info.
+    pub fn all(self) -> OffsetPaginator<'a, BeatmapsetExtended> {
+        let (offset, limit) = (self.offset, self.limit);
+
+        let fetch = move |offset, limit| -> PageFuture<'a, Vec<BeatmapsetExtended>> {
+            Box::pin(self.clone().offset(offset).limit(limit).into_future())
+        };
+
+        OffsetPaginator::new(
+            fetch,
+            |mapset| u64::from(mapset.mapset_id),
+            offset,
+            limit,
+            100,
+            None,
+        )
+    }
 }
 
 into_future! {
@@ -255,7 +276,7 @@ into_future! {
 
 /// Get a user's kudosu history as a vec of [`KudosuHistory`].
 #[must_use = "requests must be configured and executed"]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
 pub struct GetUserKudosu<'a> {
     #[serde(skip)]
     osu: &'a Osu,
@@ -291,6 +312,18 @@ impl<'a> GetUserKudosu<'a> {
 
         self
     }
+
+    /// Fetch all kudosu history entries by paging through offsets.
+    /// See [`OffsetPaginator`] for more info.
+    pub fn all(self) -> OffsetPaginator<'a, KudosuHistory> {
+        let (offset, limit) = (self.offset, self.limit);
+
+        let fetch = move |offset, limit| -> PageFuture<'a, Vec<KudosuHistory>> {
+            Box::pin(self.clone().offset(offset).limit(limit).into_future())
+        };
+
+        OffsetPaginator::new(fetch, |entry| u64::from(entry.id), offset, limit, 100, None)
+    }
 }
 
 into_future! {
@@ -305,7 +338,7 @@ into_future! {
 
 /// Get the most played beatmaps of a user as a vec of [`MostPlayedMap`].
 #[must_use = "requests must be configured and executed"]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
 pub struct GetUserMostPlayed<'a> {
     #[serde(skip)]
     osu: &'a Osu,
@@ -341,6 +374,18 @@ impl<'a> GetUserMostPlayed<'a> {
 
         self
     }
+
+    /// Fetch all most played maps by paging through offsets.
+    /// See [`OffsetPaginator`] for more info.
+    pub fn all(self) -> OffsetPaginator<'a, MostPlayedMap> {
+        let (offset, limit) = (self.offset, self.limit);
+
+        let fetch = move |offset, limit| -> PageFuture<'a, Vec<MostPlayedMap>> {
+            Box::pin(self.clone().offset(offset).limit(limit).into_future())
+        };
+
+        OffsetPaginator::new(fetch, |map| u64::from(map.map_id), offset, limit, 51, None)
+    }
 }
 
 into_future! {
@@ -360,7 +405,7 @@ into_future! {
 
 /// Get a vec of [`Event`] of a user.
 #[must_use = "requests must be configured and executed"]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
 pub struct GetRecentActivity<'a> {
     #[serde(skip)]
     osu: &'a Osu,
@@ -396,6 +441,25 @@ impl<'a> GetRecentActivity<'a> {
 
         self
     }
+
+    /// Fetch all recent events by paging through offsets.
+    /// See [`OffsetPaginator`] for more info.
+    pub fn all(self) -> OffsetPaginator<'a, Event> {
+        let (offset, limit) = (self.offset, self.limit);
+
+        let fetch = move |offset, limit| -> PageFuture<'a, Vec<Event>> {
+            Box::pin(self.clone().offset(offset).limit(limit).into_future())
+        };
+
+        OffsetPaginator::new(
+            fetch,
+            |event| u64::from(event.event_id),
+            offset,
+            limit,
+            100,
+            None,
+        )
+    }
 }
 
 into_future! {
@@ -434,7 +498,7 @@ impl ScoreType {
 /// [`firsts`](crate::request::GetUserScores::firsts),
 /// or [`recent`](crate::request::GetUserScores::recent), it defaults to `best`.
 #[must_use = "requests must be configured and executed"]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
 pub struct GetUserScores<'a> {
     #[serde(skip)]
     osu: &'a Osu,
@@ -553,6 +617,21 @@ impl<'a> GetUserScores<'a> {
 
         self
     }
+
+    /// Fetch all scores of the specified type by paging through offsets.
+    ///
+    /// The osu!api provides at most the top 200 [`best`](GetUserScores::best)
+    /// scores. See [`OffsetPaginator`] for more info.
+    pub fn all(self) -> OffsetPaginator<'a, Score> {
+        let max_offset = matches!(self.score_type, ScoreType::Best).then_some(200);
+        let (offset, limit) = (self.offset, self.limit);
+
+        let fetch = move |offset, limit| -> PageFuture<'a, Vec<Score>> {
+            Box::pin(self.clone().offset(offset).limit(limit).into_future())
+        };
+
+        OffsetPaginator::new(fetch, |score| score.id, offset, limit, 100, max_offset)
+    }
 }
 
 into_future! {
@@ -616,3 +695,34 @@ into_future! {
         Ok(users.0)
     }
 }
+
+/// Get a [`BulkLookup`] of [`User`]s for any amount of user ids.
+#[must_use = "requests must be configured and executed"]
+pub struct GetUsersBulk<'a> {
+    osu: &'a Osu,
+    user_ids: Vec<u32>,
+}
+
+impl<'a> GetUsersBulk<'a> {
+    pub(crate) fn new<I>(osu: &'a Osu, user_ids: I) -> Self
+    where
+        I: IntoIterator<Item = u32>,
+    {
+        Self {
+            osu,
+            user_ids: bulk::unique_ids(user_ids),
+        }
+    }
+}
+
+impl<'a> IntoFuture for GetUsersBulk<'a> {
+    type Output = OsuResult<BulkLookup<User>>;
+    type IntoFuture = BulkFuture<'a, User>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let Self { osu, user_ids } = self;
+        let fetch = move |chunk: &[u32]| GetUsers::new(osu, chunk.iter().copied()).into_future();
+
+        Box::pin(bulk::fetch_chunked(user_ids, fetch, |user| user.user_id))
+    }
+}
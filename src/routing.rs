@@ -10,6 +10,10 @@ use std::{borrow::Cow, fmt::Write};
 pub(crate) enum Route {
     GetBeatmap,
     GetBeatmaps,
+    GetBeatmapPack {
+        tag: Box<str>,
+    },
+    GetBeatmapPacks,
     PostBeatmapDifficultyAttributes {
         map_id: u32,
     },
@@ -28,13 +32,48 @@ pub(crate) enum Route {
         mapset_id: u32,
     },
     GetBeatmapsetFromMapId,
+    GetBeatmapsetDiscussionPosts,
+    GetBeatmapsetDiscussions,
+    GetBeatmapsetDiscussionVotes,
     GetBeatmapsetEvents,
     GetBeatmapsetSearch,
+    DeleteChatChannelUser {
+        channel_id: u64,
+        user_id: u32,
+    },
+    GetChatChannel {
+        channel_id: u64,
+    },
+    GetChatChannels,
+    GetChatMessages {
+        channel_id: u64,
+    },
+    PostChatAck,
+    PostChatMessage {
+        channel_id: u64,
+    },
+    PostChatPrivateMessage,
+    PutChatChannelUser {
+        channel_id: u64,
+        user_id: u32,
+    },
     GetComments,
     GetEvents,
+    GetForum {
+        forum_id: u32,
+    },
     GetForumPosts {
         topic_id: u64,
     },
+    GetForums,
+    GetForumTopics,
+    PostForumTopic,
+    PostForumTopicReply {
+        topic_id: u64,
+    },
+    PutForumPost {
+        post_id: u64,
+    },
     GetFriends,
     GetMatch {
         match_id: Option<u32>,
@@ -45,6 +84,10 @@ pub(crate) enum Route {
     GetOwnData {
         mode: Option<GameMode>,
     },
+    GetPlaylistScores {
+        room_id: u64,
+        playlist_item_id: u64,
+    },
     GetRankings {
         mode: GameMode,
         ranking_type: RankingType,
@@ -60,6 +103,13 @@ pub(crate) enum Route {
         mode: Option<GameMode>,
         score_id: u64,
     },
+    GetRoom {
+        room_id: u64,
+    },
+    GetRoomLeaderboard {
+        room_id: u64,
+    },
+    GetRooms,
     GetScores,
     GetSeasonalBackgrounds,
     GetSpotlights,
@@ -92,6 +142,8 @@ impl Route {
         match self {
             Self::GetBeatmap => (Method::Get, "beatmaps/lookup".into()),
             Self::GetBeatmaps => (Method::Get, "beatmaps".into()),
+            Self::GetBeatmapPack { tag } => (Method::Get, format!("beatmaps/packs/{tag}").into()),
+            Self::GetBeatmapPacks => (Method::Get, "beatmaps/packs".into()),
             Self::PostBeatmapDifficultyAttributes { map_id } => {
                 (Method::Post, format!("beatmaps/{map_id}/attributes").into())
             }
@@ -110,13 +162,59 @@ impl Route {
                 (Method::Get, format!("beatmapsets/{mapset_id}").into())
             }
             Self::GetBeatmapsetFromMapId => (Method::Get, "beatmapsets/lookup".into()),
+            Self::GetBeatmapsetDiscussionPosts => {
+                (Method::Get, "beatmapsets/discussions/posts".into())
+            }
+            Self::GetBeatmapsetDiscussions => (Method::Get, "beatmapsets/discussions".into()),
+            Self::GetBeatmapsetDiscussionVotes => {
+                (Method::Get, "beatmapsets/discussions/votes".into())
+            }
             Self::GetBeatmapsetEvents => (Method::Get, "beatmapsets/events".into()),
             Self::GetBeatmapsetSearch => (Method::Get, "beatmapsets/search".into()),
+            Self::DeleteChatChannelUser {
+                channel_id,
+                user_id,
+            } => (
+                Method::Delete,
+                format!("chat/channels/{channel_id}/users/{user_id}").into(),
+            ),
+            Self::GetChatChannel { channel_id } => {
+                (Method::Get, format!("chat/channels/{channel_id}").into())
+            }
+            Self::GetChatChannels => (Method::Get, "chat/channels".into()),
+            Self::GetChatMessages { channel_id } => (
+                Method::Get,
+                format!("chat/channels/{channel_id}/messages").into(),
+            ),
+            Self::PostChatAck => (Method::Post, "chat/ack".into()),
+            Self::PostChatMessage { channel_id } => (
+                Method::Post,
+                format!("chat/channels/{channel_id}/messages").into(),
+            ),
+            Self::PostChatPrivateMessage => (Method::Post, "chat/new".into()),
+            Self::PutChatChannelUser {
+                channel_id,
+                user_id,
+            } => (
+                Method::Put,
+                format!("chat/channels/{channel_id}/users/{user_id}").into(),
+            ),
             Self::GetComments => (Method::Get, "comments".into()),
             Self::GetEvents => (Method::Get, "events".into()),
+            Self::GetForum { forum_id } => (Method::Get, format!("forums/{forum_id}").into()),
             Self::GetForumPosts { topic_id } => {
                 (Method::Get, format!("forums/topics/{topic_id}").into())
             }
+            Self::GetForums => (Method::Get, "forums".into()),
+            Self::GetForumTopics => (Method::Get, "forums/topics".into()),
+            Self::PostForumTopic => (Method::Post, "forums/topics".into()),
+            Self::PostForumTopicReply { topic_id } => (
+                Method::Post,
+                format!("forums/topics/{topic_id}/reply").into(),
+            ),
+            Self::PutForumPost { post_id } => {
+                (Method::Put, format!("forums/posts/{post_id}").into())
+            }
             Self::GetFriends => (Method::Get, "friends".into()),
             Self::GetMatch { match_id } => {
                 let path = match match_id {
@@ -142,6 +240,13 @@ impl Route {
 
                 (Method::Get, path)
             }
+            Self::GetPlaylistScores {
+                room_id,
+                playlist_item_id,
+            } => (
+                Method::Get,
+                format!("rooms/{room_id}/playlist/{playlist_item_id}/scores").into(),
+            ),
             Self::GetRankings { mode, ranking_type } => (
                 Method::Get,
                 format!("rankings/{mode}/{}", ranking_type.as_str()).into(),
@@ -164,6 +269,11 @@ impl Route {
                 };
                 (Method::Get, path)
             }
+            Self::GetRoom { room_id } => (Method::Get, format!("rooms/{room_id}").into()),
+            Self::GetRoomLeaderboard { room_id } => {
+                (Method::Get, format!("rooms/{room_id}/leaderboard").into())
+            }
+            Self::GetRooms => (Method::Get, "rooms".into()),
             Self::GetScores => (Method::Get, "scores".into()),
             Self::GetSeasonalBackgrounds => (Method::Get, "seasonal-backgrounds".into()),
             Self::GetSpotlights => (Method::Get, "spotlights".into()),
@@ -203,22 +313,40 @@ impl Route {
         }
     }
 
-    #[cfg(feature = "metrics")]
     pub(crate) const fn name(&self) -> &'static str {
         match self {
             Self::GetBeatmap => "GetBeatmap",
             Self::GetBeatmaps => "GetBeatmaps",
+            Self::GetBeatmapPack { .. } => "GetBeatmapPack",
+            Self::GetBeatmapPacks => "GetBeatmapPacks",
             Self::PostBeatmapDifficultyAttributes { .. } => "PostBeatmapDifficultyAttributes",
             Self::GetBeatmapScores { .. } => "GetBeatmapScores",
             Self::GetBeatmapUserScore { .. } => "GetBeatmapUserScore",
             Self::GetBeatmapUserScores { .. } => "GetBeatmapUserScores",
             Self::GetBeatmapset { .. } => "GetBeatmapset",
             Self::GetBeatmapsetFromMapId => "GetBeatmapsetFromMapId",
+            Self::GetBeatmapsetDiscussionPosts => "GetBeatmapsetDiscussionPosts",
+            Self::GetBeatmapsetDiscussions => "GetBeatmapsetDiscussions",
+            Self::GetBeatmapsetDiscussionVotes => "GetBeatmapsetDiscussionVotes",
             Self::GetBeatmapsetEvents => "GetBeatmapsetEvents",
             Self::GetBeatmapsetSearch => "GetBeatmapsetSearch",
+            Self::DeleteChatChannelUser { .. } => "DeleteChatChannelUser",
+            Self::GetChatChannel { .. } => "GetChatChannel",
+            Self::GetChatChannels => "GetChatChannels",
+            Self::GetChatMessages { .. } => "GetChatMessages",
+            Self::PostChatAck => "PostChatAck",
+            Self::PostChatMessage { .. } => "PostChatMessage",
+            Self::PostChatPrivateMessage => "PostChatPrivateMessage",
+            Self::PutChatChannelUser { .. } => "PutChatChannelUser",
             Self::GetComments => "GetComments",
             Self::GetEvents => "GetEvents",
+            Self::GetForum { .. } => "GetForum",
             Self::GetForumPosts { .. } => "GetForumPosts",
+            Self::GetForums => "GetForums",
+            Self::GetForumTopics => "GetForumTopics",
+            Self::PostForumTopic => "PostForumTopic",
+            Self::PostForumTopicReply { .. } => "PostForumTopicReply",
+            Self::PutForumPost { .. } => "PutForumPost",
             Self::GetFriends => "GetFriends",
             Self::GetMatch { match_id } => match match_id {
                 Some(_) => "GetMatch/match_id",
@@ -226,6 +354,7 @@ impl Route {
             },
             Self::GetNews { .. } => "GetNews",
             Self::GetOwnData { .. } => "GetOwnData",
+            Self::GetPlaylistScores { .. } => "GetPlaylistScores",
             Self::GetRankings { ranking_type, .. } => match ranking_type {
                 RankingType::Charts => "GetRankings/Charts",
                 RankingType::Country => "GetRankings/Country",
@@ -236,6 +365,9 @@ impl Route {
             Self::GetRecentActivity { .. } => "GetRecentActivity",
             Self::GetReplay { .. } => "GetReplay",
             Self::GetScore { .. } => "GetScore",
+            Self::GetRoom { .. } => "GetRoom",
+            Self::GetRoomLeaderboard { .. } => "GetRoomLeaderboard",
+            Self::GetRooms => "GetRooms",
             Self::GetScores => "GetScores",
             Self::GetSeasonalBackgrounds => "GetSeasonalBackgrounds",
             Self::GetSpotlights => "GetSpotlights",
@@ -0,0 +1,367 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    convert::Infallible,
+    fs,
+    io::Error as IoError,
+    net::SocketAddr,
+    path::Path,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+
+use bytes::Bytes;
+use http_body_util::{BodyExt, Collected, Full};
+use hyper::{
+    body::Incoming,
+    header::{HeaderName, HeaderValue, CONTENT_TYPE},
+    server::conn::http1,
+    service::service_fn,
+    HeaderMap, Method, Request, Response, StatusCode,
+};
+use hyper_util::rt::TokioIo;
+use tokio::{net::TcpListener, sync::oneshot};
+
+use crate::OsuBuilder;
+
+const API_PREFIX: &str = "/api/v2/";
+const OAUTH_PREFIX: &str = "/oauth/";
+
+/// A local stand-in for the osu!api to test the request pipeline offline.
+///
+/// The server listens on a random port of `127.0.0.1` and serves
+///   - `POST /oauth/token` with a dummy token
+///   - all mocked routes, see [`MockServer::mock`] and [`MockServer::mock_once`]
+///   - fixture files, see [`MockServer::fixtures`]
+///
+/// Requests to any other route are answered with a 404.
+///
+/// The server shuts down when it is dropped.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rosu_v2::{prelude::*, testing::{MockResponse, MockServer}};
+///
+/// # let _ = async {
+/// let server = MockServer::start().await.unwrap();
+///
+/// server.mock_once("GET", "users/2", MockResponse::status(StatusCode::SERVICE_UNAVAILABLE));
+/// server.mock("GET", "users/2", MockResponse::json(r#"{"id": 2, ...}"#));
+///
+/// let osu = server.osu_builder().build().await.unwrap();
+/// # };
+/// ```
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<MockState>,
+    shutdown_tx: Option<oneshot::Sender<()>>,
+}
+
+impl MockServer {
+    /// Start a new [`MockServer`] in the background.
+    pub async fn start() -> Result<Self, IoError> {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(MockState::default());
+        let (shutdown_tx, mut shutdown_rx) = oneshot::channel();
+
+        let state_clone = Arc::clone(&state);
+
+        tokio::spawn(async move {
+            loop {
+                let stream = tokio::select! {
+                    _ = &mut shutdown_rx => return,
+                    res = listener.accept() => match res {
+                        Ok((stream, _)) => stream,
+                        Err(err) => {
+                            warn!(?err, "Mock server failed to accept connection");
+
+                            continue;
+                        }
+                    },
+                };
+
+                let state = Arc::clone(&state_clone);
+
+                tokio::spawn(async move {
+                    let service = service_fn(move |req| {
+                        let state = Arc::clone(&state);
+
+                        async move { Ok::<_, Infallible>(state.handle(req).await) }
+                    });
+
+                    let conn =
+                        http1::Builder::new().serve_connection(TokioIo::new(stream), service);
+
+                    if let Err(err) = conn.await {
+                        debug!(?err, "Mock server connection error");
+                    }
+                });
+            }
+        });
+
+        Ok(Self {
+            addr,
+            state,
+            shutdown_tx: Some(shutdown_tx),
+        })
+    }
+
+    /// The address the server listens on.
+    pub const fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// The base URL for API requests, to be passed to
+    /// [`OsuBuilder::api_base_url`].
+    pub fn api_url(&self) -> String {
+        format!("http://{}{API_PREFIX}", self.addr)
+    }
+
+    /// The base URL for OAuth requests, to be passed to
+    /// [`OsuBuilder::oauth_base_url`].
+    pub fn oauth_url(&self) -> String {
+        format!("http://{}{OAUTH_PREFIX}", self.addr)
+    }
+
+    /// An [`OsuBuilder`] with dummy client credentials whose base URLs point
+    /// to this server.
+    pub fn osu_builder(&self) -> OsuBuilder {
+        OsuBuilder::new()
+            .client_id(1)
+            .client_secret("mock")
+            .api_base_url(self.api_url())
+            .oauth_base_url(self.oauth_url())
+    }
+
+    /// Respond to all requests of the given method and route with the given
+    /// response.
+    ///
+    /// The route is the API path without leading slash and without query,
+    /// e.g. `"users/2/osu"`. Previously mocked responses for the same route
+    /// are replaced.
+    pub fn mock(&self, method: &str, route: &str, response: MockResponse) {
+        self.state
+            .routes()
+            .entry(route_key(method, route))
+            .or_default()
+            .fallback = Some(response);
+    }
+
+    /// Respond to the next request of the given method and route with the
+    /// given response.
+    ///
+    /// Multiple one-time responses for the same route are served in the
+    /// order they were added. Once all of them are consumed, the response
+    /// given to [`MockServer::mock`] is served.
+    pub fn mock_once(&self, method: &str, route: &str, response: MockResponse) {
+        self.state
+            .routes()
+            .entry(route_key(method, route))
+            .or_default()
+            .once
+            .push_back(response);
+    }
+
+    /// Load all `.json` files within the given directory as `GET` responses.
+    ///
+    /// The path of a file relative to the directory determines its route so
+    /// e.g. `{dir}/users/2/osu.json` will be served for `GET users/2/osu`.
+    ///
+    /// No fixtures are shipped with this crate so only routes for which the
+    /// directory contains a file are served. Every other route needs to be
+    /// mocked through [`MockServer::mock`] or [`MockServer::mock_once`].
+    pub fn fixtures(&self, dir: impl AsRef<Path>) -> Result<(), IoError> {
+        fn visit(server: &MockServer, root: &Path, dir: &Path) -> Result<(), IoError> {
+            for entry in fs::read_dir(dir)? {
+                let path = entry?.path();
+
+                if path.is_dir() {
+                    visit(server, root, &path)?;
+                } else if path.extension().is_some_and(|ext| ext == "json") {
+                    let Ok(relative) = path
+                        .with_extension("")
+                        .strip_prefix(root)
+                        .map(Path::to_owned)
+                    else {
+                        continue;
+                    };
+
+                    let route = relative
+                        .components()
+                        .map(|component| component.as_os_str().to_string_lossy())
+                        .collect::<Vec<_>>()
+                        .join("/");
+
+                    server.mock("GET", &route, MockResponse::json(fs::read(&path)?));
+                }
+            }
+
+            Ok(())
+        }
+
+        let dir = dir.as_ref();
+
+        visit(self, dir, dir)
+    }
+
+    /// All requests the server received so far, including token requests.
+    pub fn received(&self) -> Vec<MockRequest> {
+        self.state.received().clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(tx) = self.shutdown_tx.take() {
+            let _ = tx.send(());
+        }
+    }
+}
+
+/// A scripted response of a [`MockServer`].
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+    delay: Option<Duration>,
+}
+
+impl MockResponse {
+    /// A 200 response with the given JSON body.
+    pub fn json(body: impl Into<Bytes>) -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        Self {
+            status: StatusCode::OK,
+            headers,
+            body: body.into(),
+            delay: None,
+        }
+    }
+
+    /// A response with the given status and an API error body.
+    pub fn status(status: StatusCode) -> Self {
+        Self::json(r#"{"error":null}"#).with_status(status)
+    }
+
+    /// Overwrite the status of the response.
+    #[must_use]
+    pub const fn with_status(mut self, status: StatusCode) -> Self {
+        self.status = status;
+
+        self
+    }
+
+    /// Add a header to the response.
+    #[must_use]
+    pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.append(name, value);
+
+        self
+    }
+
+    /// Wait for the given duration before responding.
+    #[must_use]
+    pub const fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+
+        self
+    }
+}
+
+/// A request received by a [`MockServer`].
+#[derive(Clone, Debug)]
+pub struct MockRequest {
+    /// The request method
+    pub method: Method,
+    /// The request path, e.g. `/api/v2/users/2`
+    pub path: String,
+    /// The request query, if any
+    pub query: Option<String>,
+    /// The request headers
+    pub headers: HeaderMap,
+    /// The request body
+    pub body: Bytes,
+}
+
+#[derive(Default)]
+struct MockRoute {
+    once: VecDeque<MockResponse>,
+    fallback: Option<MockResponse>,
+}
+
+#[derive(Default)]
+struct MockState {
+    routes: Mutex<HashMap<String, MockRoute>>,
+    received: Mutex<Vec<MockRequest>>,
+}
+
+impl MockState {
+    fn routes(&self) -> MutexGuard<'_, HashMap<String, MockRoute>> {
+        self.routes.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn received(&self) -> MutexGuard<'_, Vec<MockRequest>> {
+        self.received.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    async fn handle(&self, req: Request<Incoming>) -> Response<Full<Bytes>> {
+        let (parts, body) = req.into_parts();
+        let body = body
+            .collect()
+            .await
+            .map(Collected::to_bytes)
+            .unwrap_or_default();
+        let path = parts.uri.path().to_owned();
+
+        self.received().push(MockRequest {
+            method: parts.method.clone(),
+            path: path.clone(),
+            query: parts.uri.query().map(str::to_owned),
+            headers: parts.headers,
+            body,
+        });
+
+        let response = if let Some(route) = path.strip_prefix(API_PREFIX) {
+            let mut routes = self.routes();
+
+            routes
+                .get_mut(&route_key(parts.method.as_str(), route))
+                .and_then(|route| route.once.pop_front().or_else(|| route.fallback.clone()))
+        } else if parts.method == Method::POST && path == format!("{OAUTH_PREFIX}token") {
+            let token = r#"{"access_token":"mock","expires_in":86400,"token_type":"Bearer"}"#;
+
+            Some(MockResponse::json(token))
+        } else {
+            None
+        };
+
+        let MockResponse {
+            status,
+            headers,
+            body,
+            delay,
+        } = response.unwrap_or_else(|| MockResponse::status(StatusCode::NOT_FOUND));
+
+        if let Some(delay) = delay {
+            tokio::time::sleep(delay).await;
+        }
+
+        let mut resp = Response::new(Full::new(body));
+        *resp.status_mut() = status;
+        *resp.headers_mut() = headers;
+
+        resp
+    }
+}
+
+fn route_key(method: &str, route: &str) -> String {
+    format!(
+        "{} {}",
+        method.to_ascii_uppercase(),
+        route.trim_matches('/')
+    )
+}
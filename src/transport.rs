@@ -0,0 +1,105 @@
+use std::{error::Error as StdError, future::Future, pin::Pin};
+
+use bytes::Bytes;
+use http_body_util::{combinators::BoxBody, BodyExt, Full};
+use hyper::{Request, Response};
+use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
+use hyper_util::{
+    client::legacy::{
+        connect::{Connect, HttpConnector},
+        Client as HyperClient,
+    },
+    rt::TokioExecutor,
+};
+
+use crate::{error::OsuError, OsuResult};
+
+/// Error type returned by a [`Transport`].
+pub type TransportError = Box<dyn StdError + Send + Sync>;
+
+/// Body of a [`Response`] returned by a [`Transport`].
+pub type ResponseBody = BoxBody<Bytes, TransportError>;
+
+/// Future returned by [`Transport::request`].
+pub type TransportFuture =
+    Pin<Box<dyn Future<Output = Result<Response<ResponseBody>, TransportError>> + Send>>;
+
+/// The HTTP client used to send requests to the osu!api.
+///
+/// By default, [`HyperTransport`] is used. Implementing this trait allows
+/// providing a custom client through [`OsuBuilder::transport`], e.g. to use
+/// a custom connector, serve responses from memory in tests, or record
+/// traffic.
+///
+/// Both API requests and token requests go through the transport.
+/// Timeouts, retries, and ratelimiting are handled by the [`Osu`] client
+/// so implementations need not worry about those.
+///
+/// [`OsuBuilder::transport`]: crate::OsuBuilder::transport
+/// [`Osu`]: crate::Osu
+pub trait Transport: Send + Sync + 'static {
+    /// Send the given request and return its response.
+    fn request(&self, req: Request<Full<Bytes>>) -> TransportFuture;
+}
+
+impl<T: Transport + ?Sized> Transport for Box<T> {
+    fn request(&self, req: Request<Full<Bytes>>) -> TransportFuture {
+        (**self).request(req)
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for std::sync::Arc<T> {
+    fn request(&self, req: Request<Full<Bytes>>) -> TransportFuture {
+        (**self).request(req)
+    }
+}
+
+/// The default [`Transport`], a [`hyper_util`] client with a rustls connector.
+pub struct HyperTransport<C = HttpsConnector<HttpConnector>> {
+    client: HyperClient<C, Full<Bytes>>,
+}
+
+impl HyperTransport {
+    /// Create a new [`HyperTransport`] using native root certificates and
+    /// supporting both HTTP/1 and HTTP/2.
+    ///
+    /// Errors if the native roots are missing to build the https connector.
+    pub fn new() -> OsuResult<Self> {
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+
+        let connector = HttpsConnectorBuilder::new()
+            .with_native_roots()
+            .map_err(|source| OsuError::ConnectorRoots { source })?
+            .https_or_http()
+            .enable_http1()
+            .enable_http2()
+            .wrap_connector(http);
+
+        let client = HyperClient::builder(TokioExecutor::new()).build(connector);
+
+        Ok(Self { client })
+    }
+}
+
+impl<C> HyperTransport<C> {
+    /// Use the given [`hyper_util`] client, e.g. one with a custom connector.
+    pub const fn from_client(client: HyperClient<C, Full<Bytes>>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> Transport for HyperTransport<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    fn request(&self, req: Request<Full<Bytes>>) -> TransportFuture {
+        let future = self.client.request(req);
+
+        Box::pin(async move {
+            let resp = future.await?;
+
+            Ok(resp.map(|body| body.map_err(TransportError::from).boxed()))
+        })
+    }
+}
@@ -0,0 +1,1945 @@
+#![cfg(feature = "testing")]
+
+extern crate rosu_v2;
+
+use std::{
+    future::IntoFuture,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use bytes::Bytes;
+use eyre::Result;
+use futures::{StreamExt, TryStreamExt};
+use http_body_util::Full;
+use hyper::{
+    header::{HeaderName, HeaderValue, ETAG, RETRY_AFTER},
+    Request,
+};
+use rosu_v2::{
+    error::OsuError,
+    middleware::{Middleware, MiddlewareResponse, RequestInfo},
+    prelude::{EventType, GameMode, MatchEvent, MatchUpdate, MatchesCursor, StatusCode},
+    testing::{MockResponse, MockServer},
+    Priority, ResponseCache, RetryPolicy,
+};
+
+const X_RATELIMIT_LIMIT: HeaderName = HeaderName::from_static("x-ratelimit-limit");
+const X_RATELIMIT_REMAINING: HeaderName = HeaderName::from_static("x-ratelimit-remaining");
+
+async fn server() -> Result<MockServer> {
+    let server = MockServer::start().await?;
+    server.fixtures(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures"))?;
+
+    Ok(server)
+}
+
+#[tokio::test]
+async fn fixture() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    let page = osu.wiki("en").page("Main_page").await?;
+    assert_eq!(page.title, "Main page");
+
+    let received = server.received();
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[0].path, "/oauth/token");
+    assert_eq!(received[1].path, "/api/v2/wiki/en/Main_page");
+    assert_eq!(received[1].headers["authorization"], "Bearer mock");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn not_found() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    let err = osu.wiki("en").page("Missing").await.unwrap_err();
+    assert!(matches!(err, OsuError::NotFound), "{err:?}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn scripted_statuses() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().retries(0).build().await?;
+
+    let route = "wiki/en/Main_page";
+    let unavailable = MockResponse::status(StatusCode::SERVICE_UNAVAILABLE);
+    let ratelimited = MockResponse::status(StatusCode::TOO_MANY_REQUESTS);
+
+    server.mock_once("GET", route, unavailable);
+    server.mock_once("GET", route, ratelimited);
+
+    let err = osu.wiki("en").page("Main_page").await.unwrap_err();
+    assert!(
+        matches!(err, OsuError::ServiceUnavailable { .. }),
+        "{err:?}"
+    );
+
+    let err = osu.wiki("en").page("Main_page").await.unwrap_err();
+    assert!(
+        matches!(err, OsuError::Response { status, .. } if status == StatusCode::TOO_MANY_REQUESTS),
+        "{err:?}"
+    );
+
+    // One-time responses are consumed, the fixture is served again
+    osu.wiki("en").page("Main_page").await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn delayed() -> Result<()> {
+    let server = server().await?;
+
+    let osu = server
+        .osu_builder()
+        .timeout(Duration::from_millis(100))
+        .retries(1)
+        .build()
+        .await?;
+
+    let route = "wiki/en/Main_page";
+    let delayed = MockResponse::status(StatusCode::OK).with_delay(Duration::from_secs(1));
+
+    // First attempt times out, the retry succeeds
+    server.mock_once("GET", route, delayed.clone());
+    osu.wiki("en").page("Main_page").await?;
+
+    // Both attempts time out
+    server.mock_once("GET", route, delayed.clone());
+    server.mock_once("GET", route, delayed);
+
+    let err = osu.wiki("en").page("Main_page").await.unwrap_err();
+    assert!(matches!(err, OsuError::RequestTimeout), "{err:?}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn retry_statuses() -> Result<()> {
+    let server = server().await?;
+
+    let policy = RetryPolicy::new()
+        .max_retries(2)
+        .initial_backoff(Duration::from_millis(10));
+
+    let osu = server.osu_builder().retry_policy(policy).build().await?;
+
+    let route = "wiki/en/Main_page";
+    let unavailable = MockResponse::status(StatusCode::SERVICE_UNAVAILABLE);
+    let ratelimited = MockResponse::status(StatusCode::TOO_MANY_REQUESTS)
+        .with_header(RETRY_AFTER, HeaderValue::from_static("1"));
+
+    server.mock_once("GET", route, unavailable.clone());
+    server.mock_once("GET", route, ratelimited);
+
+    // 503 is retried immediately, 429 after waiting for `Retry-After`
+    let start = Instant::now();
+    osu.wiki("en").page("Main_page").await?;
+    assert!(start.elapsed() >= Duration::from_secs(1));
+
+    // Retries are exhausted
+    for _ in 0..3 {
+        server.mock_once("GET", route, unavailable.clone());
+    }
+
+    let err = osu.wiki("en").page("Main_page").await.unwrap_err();
+    assert!(
+        matches!(err, OsuError::ServiceUnavailable { .. }),
+        "{err:?}"
+    );
+
+    // Statuses not contained in the policy are not retried
+    server.mock_once("GET", route, MockResponse::status(StatusCode::FORBIDDEN));
+
+    let err = osu.wiki("en").page("Main_page").await.unwrap_err();
+    assert!(
+        matches!(err, OsuError::Response { status, .. } if status == StatusCode::FORBIDDEN),
+        "{err:?}"
+    );
+
+    let wiki_requests = server
+        .received()
+        .iter()
+        .filter(|req| req.path.ends_with(route))
+        .count();
+
+    assert_eq!(wiki_requests, 3 + 3 + 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn adaptive_ratelimit() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    assert_eq!(osu.ratelimit_budget(), None);
+
+    let route = "wiki/en/Main_page";
+    let fixture = include_str!("fixtures/wiki/en/Main_page.json");
+
+    let low_budget = MockResponse::json(fixture)
+        .with_header(X_RATELIMIT_LIMIT, HeaderValue::from_static("60"))
+        .with_header(X_RATELIMIT_REMAINING, HeaderValue::from_static("1"));
+
+    server.mock_once("GET", route, low_budget);
+    osu.wiki("en").page("Main_page").await?;
+
+    let budget = osu.ratelimit_budget().unwrap();
+    assert_eq!((budget.limit, budget.remaining), (60, 1));
+    assert_eq!(budget.paused_for, None);
+
+    // With a low budget, requests are paced at one per second
+    let start = Instant::now();
+    osu.wiki("en").page("Main_page").await?;
+    assert!(start.elapsed() >= Duration::from_secs(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn priorities() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().ratelimit(1).build().await?;
+    let background = osu.with_priority(Priority::Low);
+
+    let fixture = include_str!("fixtures/wiki/en/Main_page.json");
+
+    for page in ["Blocker", "Low1", "Low2", "Low3", "High"] {
+        server.mock(
+            "GET",
+            &format!("wiki/en/{page}"),
+            MockResponse::json(fixture),
+        );
+    }
+
+    let high_priority = osu.with_priority(Priority::High);
+
+    // Use up the ratelimiter's initial permit so that the blocker has to wait
+    // while the other requests join the queue
+    osu.wiki("en").page("Main_page").await?;
+
+    let (blocker, low1, low2, low3, high) = tokio::join!(
+        osu.wiki("en").page("Blocker").into_future(),
+        background.wiki("en").page("Low1").into_future(),
+        background.wiki("en").page("Low2").into_future(),
+        background.wiki("en").page("Low3").into_future(),
+        high_priority.wiki("en").page("High").into_future(),
+    );
+
+    for res in [blocker, low1, low2, low3, high] {
+        res?;
+    }
+
+    let pages: Vec<_> = server
+        .received()
+        .into_iter()
+        .filter_map(|req| req.path.strip_prefix("/api/v2/wiki/en/").map(str::to_owned))
+        .collect();
+
+    assert_eq!(
+        pages,
+        ["Main_page", "Blocker", "High", "Low1", "Low2", "Low3"]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unpolled_request() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    // A request that is created but never polled must not block others
+    let _unpolled = osu.wiki("en").page("Main_page").into_future();
+
+    let page = tokio::time::timeout(Duration::from_secs(5), osu.wiki("en").page("Main_page"))
+        .await
+        .expect("request must not wait for the unpolled one")?;
+    assert_eq!(page.title, "Main page");
+
+    Ok(())
+}
+
+/// Tags requests with a header, records responses, answers requests to
+/// `wiki/de/` on its own, and turns 418s into 200s.
+#[derive(Clone, Default)]
+struct Recorder {
+    responses: Arc<Mutex<Vec<(String, StatusCode)>>>,
+}
+
+impl Middleware for Recorder {
+    fn on_request(&self, req: &mut Request<Full<Bytes>>) -> Option<MiddlewareResponse> {
+        req.headers_mut()
+            .insert("x-recorder", HeaderValue::from_static("1"));
+
+        req.uri()
+            .path()
+            .ends_with("/wiki/de/")
+            .then(|| MiddlewareResponse::new(StatusCode::NOT_FOUND, r#"{"error":null}"#))
+    }
+
+    fn on_response(&self, req: &RequestInfo, resp: &mut MiddlewareResponse) {
+        self.responses
+            .lock()
+            .unwrap()
+            .push((req.uri.path().to_owned(), resp.status));
+
+        if resp.status == StatusCode::IM_A_TEAPOT {
+            resp.status = StatusCode::OK;
+        }
+    }
+}
+
+#[tokio::test]
+async fn middleware() -> Result<()> {
+    let server = server().await?;
+    let recorder = Recorder::default();
+
+    let osu = server
+        .osu_builder()
+        .middleware(recorder.clone())
+        .build()
+        .await?;
+
+    server.mock(
+        "GET",
+        "wiki/en/Renamed",
+        MockResponse::json(std::fs::read(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/wiki/en/Main_page.json"
+        ))?)
+        .with_status(StatusCode::IM_A_TEAPOT),
+    );
+
+    let page = osu.wiki("en").page("Renamed").await?;
+    assert_eq!(page.title, "Main page");
+
+    let err = osu.wiki("de").await.unwrap_err();
+    assert!(matches!(err, OsuError::NotFound), "{err:?}");
+
+    let received = server.received();
+    assert_eq!(
+        received.len(),
+        2,
+        "short-circuited request must not be sent"
+    );
+    assert!(!received[0].headers.contains_key("x-recorder"));
+    assert_eq!(received[1].headers["x-recorder"], "1");
+
+    assert_eq!(
+        *recorder.responses.lock().unwrap(),
+        [
+            (
+                "/api/v2/wiki/en/Renamed".to_owned(),
+                StatusCode::IM_A_TEAPOT
+            ),
+            ("/api/v2/wiki/de/".to_owned(), StatusCode::NOT_FOUND),
+        ]
+    );
+
+    Ok(())
+}
+
+/// Records the paths of the responses it handles.
+#[derive(Clone, Default)]
+struct PathRecorder(Arc<Mutex<Vec<String>>>);
+
+impl Middleware for PathRecorder {
+    fn on_response(&self, req: &RequestInfo, _: &mut MiddlewareResponse) {
+        self.0.lock().unwrap().push(req.uri.path().to_owned());
+    }
+}
+
+#[tokio::test]
+async fn middleware_scope() -> Result<()> {
+    let server = server().await?;
+    let recorder = Recorder::default();
+    let later = PathRecorder::default();
+
+    let policy = RetryPolicy::new().initial_backoff(Duration::from_millis(10));
+
+    let osu = server
+        .osu_builder()
+        .retry_policy(policy)
+        .middleware(recorder.clone())
+        .middleware(later.clone())
+        .build()
+        .await?;
+
+    // Middlewares after a short-circuiting one see neither request nor
+    // response
+    let err = osu.wiki("de").await.unwrap_err();
+    assert!(matches!(err, OsuError::NotFound), "{err:?}");
+    assert!(later.0.lock().unwrap().is_empty());
+
+    // Only the response of the final attempt is observed
+    let unavailable = MockResponse::status(StatusCode::SERVICE_UNAVAILABLE);
+    server.mock_once("GET", "wiki/en/Main_page", unavailable);
+    osu.wiki("en").page("Main_page").await?;
+
+    assert_eq!(server.received().len(), 3);
+    assert_eq!(*later.0.lock().unwrap(), ["/api/v2/wiki/en/Main_page"]);
+    assert_eq!(
+        *recorder.responses.lock().unwrap(),
+        [
+            ("/api/v2/wiki/de/".to_owned(), StatusCode::NOT_FOUND),
+            ("/api/v2/wiki/en/Main_page".to_owned(), StatusCode::OK),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn response_cache() -> Result<()> {
+    let server = server().await?;
+    let cache = ResponseCache::new().ttl("GetWikiPage", Duration::from_mins(1));
+    let osu = server.osu_builder().response_cache(cache).build().await?;
+
+    // Fresh responses are served from the cache
+    let page = osu.wiki("en").page("Main_page").await?;
+    let cached = osu.wiki("en").page("Main_page").await?;
+    assert_eq!(page, cached);
+    assert_eq!(server.received().len(), 2);
+
+    osu.clear_response_cache();
+    let _ = osu.wiki("en").page("Main_page").await?;
+    assert_eq!(server.received().len(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn response_cache_revalidation() -> Result<()> {
+    let server = server().await?;
+    let cache = ResponseCache::new().ttl("GetWikiPage", Duration::ZERO);
+    let osu = server.osu_builder().response_cache(cache).build().await?;
+
+    let route = "wiki/en/Main_page";
+    let fixture = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/wiki/en/Main_page.json"
+    ))?;
+    let etag = HeaderValue::from_static("\"abc\"");
+    let not_modified = MockResponse::json("").with_status(StatusCode::NOT_MODIFIED);
+
+    server.mock_once(
+        "GET",
+        route,
+        MockResponse::json(fixture).with_header(ETAG, etag.clone()),
+    );
+    server.mock_once("GET", route, not_modified);
+
+    // Stale responses are revalidated through the ETag
+    let page = osu.wiki("en").page("Main_page").await?;
+    let revalidated = osu.wiki("en").page("Main_page").await?;
+    assert_eq!(page, revalidated);
+
+    let received = server.received();
+    assert_eq!(received.len(), 3);
+    assert!(!received[1].headers.contains_key("if-none-match"));
+    assert_eq!(received[2].headers["if-none-match"], etag);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn response_cache_evicted_during_revalidation() -> Result<()> {
+    let server = server().await?;
+    let cache = ResponseCache::new().ttl("GetWikiPage", Duration::ZERO);
+    let osu = server.osu_builder().response_cache(cache).build().await?;
+
+    let route = "wiki/en/Main_page";
+    let fixture = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/wiki/en/Main_page.json"
+    ))?;
+    let etag = HeaderValue::from_static("\"abc\"");
+    let not_modified = MockResponse::json("").with_status(StatusCode::NOT_MODIFIED);
+
+    server.mock_once(
+        "GET",
+        route,
+        MockResponse::json(fixture.clone()).with_header(ETAG, etag.clone()),
+    );
+    server.mock_once("GET", route, not_modified);
+    server.mock_once("GET", route, MockResponse::json(fixture));
+
+    let page = osu.wiki("en").page("Main_page").await?;
+
+    // The stale response is looked up when the future is created so clearing
+    // the cache afterwards evicts it before the 304 arrives
+    let fut = osu.wiki("en").page("Main_page").into_future();
+    osu.clear_response_cache();
+
+    // The request is sent again without validators to get the full response
+    let refetched = fut.await?;
+    assert_eq!(page, refetched);
+
+    let received = server.received();
+    assert_eq!(received.len(), 4);
+    assert_eq!(received[2].headers["if-none-match"], etag);
+    assert!(!received[3].headers.contains_key("if-none-match"));
+
+    Ok(())
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn user_id_cache() -> Result<()> {
+    use rosu_v2::cache::{LruUserIdCache, UserIdCache};
+
+    let server = server().await?;
+    let cache = Arc::new(LruUserIdCache::new(10));
+    cache.insert("peppy".into(), 2);
+
+    let osu = server
+        .osu_builder()
+        .user_id_cache(Arc::clone(&cache))
+        .build()
+        .await?;
+
+    server.mock("GET", "users/2/kudosu", MockResponse::json("[]"));
+
+    // The name is resolved through the cache so no user request is necessary
+    let kudosu = osu.kudosu("PePpY").await?;
+    assert!(kudosu.is_empty());
+
+    let received = server.received();
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[1].path, "/api/v2/users/2/kudosu");
+
+    Ok(())
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn reverify_cached_names() -> Result<()> {
+    use rosu_v2::cache::{UnboundedUserIdCache, UserIdCache};
+
+    let server = server().await?;
+    server.mock("GET", "users/3/kudosu", MockResponse::json("[]"));
+
+    // "peppy" used to be user 2 but is now user 3 who used to be "oldpeppy"
+    let stale_cache = || {
+        let cache = Arc::new(UnboundedUserIdCache::new());
+        cache.insert("peppy".into(), 2);
+        cache.insert("oldpeppy".into(), 3);
+
+        cache
+    };
+
+    let cache = stale_cache();
+
+    let osu = server
+        .osu_builder()
+        .user_id_cache(Arc::clone(&cache))
+        .build()
+        .await?;
+
+    let err = osu.kudosu("peppy").await.unwrap_err();
+    assert!(matches!(err, OsuError::NotFound), "{err:?}");
+
+    let cache = stale_cache();
+
+    let osu = server
+        .osu_builder()
+        .user_id_cache(Arc::clone(&cache))
+        .reverify_cached_names(true)
+        .build()
+        .await?;
+
+    let kudosu = osu.kudosu("peppy").await?;
+    assert!(kudosu.is_empty());
+
+    assert_eq!(cache.get("peppy"), Some(3));
+    assert_eq!(cache.get("oldpeppy"), None);
+
+    let paths: Vec<_> = server
+        .received()
+        .into_iter()
+        .map(|req| req.path)
+        .filter(|path| path.starts_with("/api"))
+        .collect();
+
+    assert_eq!(
+        paths,
+        [
+            "/api/v2/users/2/kudosu",
+            "/api/v2/users/2/kudosu",
+            "/api/v2/users/peppy",
+            "/api/v2/users/3/kudosu",
+        ]
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn reverify_cached_names_same_id() -> Result<()> {
+    use rosu_v2::cache::{UnboundedUserIdCache, UserIdCache};
+
+    let server = server().await?;
+    server.mock(
+        "GET",
+        "users/3/kudosu",
+        MockResponse::status(StatusCode::NOT_FOUND),
+    );
+
+    let cache = Arc::new(UnboundedUserIdCache::new());
+    cache.insert("peppy".into(), 3);
+
+    let osu = server
+        .osu_builder()
+        .user_id_cache(Arc::clone(&cache))
+        .reverify_cached_names(true)
+        .build()
+        .await?;
+
+    let err = osu.kudosu("peppy").await.unwrap_err();
+    assert!(matches!(err, OsuError::NotFound), "{err:?}");
+    assert_eq!(cache.get("peppy"), Some(3));
+
+    let paths: Vec<_> = server
+        .received()
+        .into_iter()
+        .map(|req| req.path)
+        .filter(|path| path.starts_with("/api"))
+        .collect();
+
+    assert_eq!(paths, ["/api/v2/users/3/kudosu", "/api/v2/users/peppy"]);
+
+    Ok(())
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn renamed_users_cache() -> Result<()> {
+    use rosu_v2::{
+        cache::{UnboundedUserIdCache, UserIdCache},
+        prelude::EventSort,
+    };
+
+    let server = server().await?;
+    // "b" was a previous name of user 3 but now belongs to user 7
+    let cache = Arc::new(UnboundedUserIdCache::new());
+
+    let osu = server
+        .osu_builder()
+        .user_id_cache(Arc::clone(&cache))
+        .build()
+        .await?;
+
+    let rename = |id, prev, name| {
+        format!(
+            r#"{{"created_at":"2024-01-01T00:00:00Z","id":{id},"type":"usernameChange","user":{{"username":"{name}","url":"/u/3","previousUsername":"{prev}"}}}}"#
+        )
+    };
+
+    let events = |ids: [u32; 2]| {
+        let [first, second] = ids.map(|id| match id {
+            1 => rename(1, "a", "b"),
+            _ => rename(2, "b", "c"),
+        });
+
+        MockResponse::json(format!(
+            r#"{{"events":[{first},{second}],"cursor_string":null}}"#
+        ))
+    };
+
+    server.mock_once("GET", "events", events([2, 1]));
+    server.mock_once("GET", "events", events([1, 2]));
+
+    // The newest rename wins regardless of the sort order
+    for sort in [EventSort::IdDescending, EventSort::IdAscending] {
+        cache.insert("a".into(), 3);
+        cache.insert("b".into(), 7);
+        cache.remove("c");
+
+        osu.events().sort(sort).await?;
+
+        assert_eq!(cache.get("c"), Some(3), "{sort:?}");
+        assert_eq!(cache.get("b"), Some(7), "{sort:?}");
+        assert_eq!(cache.get("a"), None, "{sort:?}");
+    }
+
+    Ok(())
+}
+
+fn match_list(ids: &[u32], cursor: Option<&str>) -> MockResponse {
+    let matches: Vec<_> = ids
+        .iter()
+        .map(|id| {
+            format!(
+                r#"{{"id":{id},"name":"match {id}","start_time":"2024-01-01T00:00:00Z","end_time":null}}"#
+            )
+        })
+        .collect();
+
+    let cursor = cursor.map_or_else(|| "null".to_owned(), |cursor| format!("\"{cursor}\""));
+
+    MockResponse::json(format!(
+        r#"{{"matches":[{}],"params":{{"limit":50,"sort":"id_desc"}},"cursor_string":{cursor}}}"#,
+        matches.join(",")
+    ))
+}
+
+#[tokio::test]
+async fn paginator() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    server.mock_once("GET", "matches", match_list(&[1, 2], Some("a")));
+    server.mock_once("GET", "matches", match_list(&[3], Some("b")));
+    server.mock_once("GET", "matches", match_list(&[4], Some("c")));
+    server.mock_once("GET", "matches", match_list(&[], None));
+
+    let first = osu.osu_matches().await?;
+    let ids: Vec<_> = first
+        .into_stream(&osu)
+        .max_items(3)
+        .map(|res| res.map(|info| info.match_id))
+        .try_collect()
+        .await?;
+
+    assert_eq!(ids, [1, 2, 3]);
+
+    // The third page was not requested since no items were needed from it
+    let received = server.received();
+    assert_eq!(received.len(), 3);
+    assert_eq!(received[2].query.as_deref(), Some("cursor_string=a"));
+
+    let pages: Vec<_> = osu
+        .osu_matches()
+        .await?
+        .into_stream(&osu)
+        .pages()
+        .try_collect()
+        .await?;
+
+    // Empty pages end the stream
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0].matches.len(), 1);
+    assert!(pages[0].has_more());
+
+    Ok(())
+}
+
+fn rankings(id: u32, page: Option<u32>) -> MockResponse {
+    let user = format!(
+        r#"{{"avatar_url":"","country_code":"AU","default_group":"default","id":{id},"is_active":true,"is_bot":false,"is_deleted":false,"is_online":false,"is_supporter":false,"pm_friends_only":false,"username":"user{id}"}}"#
+    );
+
+    let stats = format!(
+        r#"{{"count_300":0,"count_100":0,"count_50":0,"count_miss":0,"hit_accuracy":100.0,"global_rank":{id},"grade_counts":{{"ss":0,"ssh":0,"s":0,"sh":0,"a":0}},"is_ranked":true,"level":{{"current":100,"progress":0}},"maximum_combo":0,"play_count":0,"play_time":0,"pp":0.0,"ranked_score":0,"replays_watched_by_others":0,"total_hits":0,"total_score":0,"user":{user}}}"#
+    );
+
+    let cursor = page.map_or_else(|| "null".to_owned(), |page| format!(r#"{{"page":{page}}}"#));
+
+    MockResponse::json(format!(
+        r#"{{"cursor":{cursor},"ranking":[{stats}],"total":2}}"#
+    ))
+}
+
+#[tokio::test]
+async fn rankings_keep_filters() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    let route = "rankings/mania/performance";
+    server.mock_once("GET", route, rankings(1, Some(2)));
+    server.mock_once("GET", route, rankings(2, None));
+    server.mock_once("GET", route, rankings(1, Some(2)));
+    server.mock_once("GET", route, rankings(2, None));
+
+    let ids: Vec<_> = osu
+        .performance_rankings(GameMode::Mania)
+        .country("AU")
+        .variant_4k()
+        .await?
+        .into_stream(&osu)
+        .map_ok(|user| user.user_id)
+        .try_collect()
+        .await?;
+
+    assert_eq!(ids, [1, 2]);
+
+    let first = osu
+        .performance_rankings(GameMode::Mania)
+        .country("AU")
+        .variant_4k()
+        .await?;
+
+    let next = first.get_next(&osu).await.expect("missing next page")?;
+    assert_eq!(next.ranking[0].user_id, 2);
+
+    let queries: Vec<_> = server
+        .received()
+        .into_iter()
+        .skip(1)
+        .map(|req| req.query.unwrap_or_default())
+        .collect();
+
+    assert_eq!(
+        queries,
+        [
+            "country=AU&variant=4k",
+            "country=AU&variant=4k&cursor%5Bpage%5D=2",
+            "country=AU&variant=4k",
+            "country=AU&variant=4k&cursor%5Bpage%5D=2",
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn resume_cursor() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    server.mock_once("GET", "matches", match_list(&[1, 2], Some("a b")));
+    server.mock_once("GET", "matches", match_list(&[3], None));
+
+    let first = osu.osu_matches().await?;
+    let stored = serde_json::to_string(first.cursor().unwrap())?;
+    assert_eq!(stored, r#""a b""#);
+
+    let cursor: MatchesCursor = serde_json::from_str(&stored)?;
+    let next = osu.osu_matches().cursor(cursor).await?;
+    assert_eq!(next.matches[0].match_id, 3);
+    assert!(next.cursor().is_none());
+
+    let received = server.received();
+    assert_eq!(received[2].query.as_deref(), Some("cursor_string=a+b"));
+
+    Ok(())
+}
+
+fn forum_posts(ids: &[u64], sort: &str, cursor: Option<&str>) -> MockResponse {
+    let posts: Vec<_> = ids
+        .iter()
+        .map(|id| {
+            format!(
+                r#"{{"created_at":"2024-01-01T00:00:00Z","forum_id":1,"id":{id},"topic_id":7,"user_id":2,"body":{{"html":"","raw":""}}}}"#
+            )
+        })
+        .collect();
+
+    let cursor = cursor.map_or_else(|| "null".to_owned(), |cursor| format!("\"{cursor}\""));
+
+    MockResponse::json(format!(
+        r#"{{
+            "cursor_string":{cursor},
+            "posts":[{}],
+            "search":{{"limit":2,"sort":"{sort}"}},
+            "topic":{{
+                "created_at":"2024-01-01T00:00:00Z","first_post_id":1,"forum_id":1,
+                "is_locked":false,"type":"normal","last_post_id":5,"post_count":5,
+                "title":"topic","id":7,"user_id":2
+            }}
+        }}"#,
+        posts.join(",")
+    ))
+}
+
+#[tokio::test]
+async fn forum_posts_stream() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    let route = "forums/topics/7";
+    server.mock_once("GET", route, forum_posts(&[5, 4], "id_desc", Some("x")));
+    server.mock_once("GET", route, forum_posts(&[3, 2], "id_desc", Some("y")));
+    server.mock_once("GET", route, forum_posts(&[1], "id_desc", None));
+
+    let first = osu.forum_posts(7).sort_descending().limit(2).await?;
+    let ids: Vec<_> = first
+        .into_stream(&osu)
+        .map(|res| res.map(|post| post.post_id))
+        .try_collect()
+        .await?;
+
+    assert_eq!(ids, [5, 4, 3, 2, 1]);
+
+    let received = server.received();
+    assert_eq!(received.len(), 4);
+    assert_eq!(received[1].query.as_deref(), Some("sort=id_desc&limit=2"));
+    assert_eq!(
+        received[2].query.as_deref(),
+        Some("sort=id_desc&limit=2&end=3&cursor_string=x")
+    );
+    assert_eq!(
+        received[3].query.as_deref(),
+        Some("sort=id_desc&limit=2&end=1&cursor_string=y")
+    );
+
+    Ok(())
+}
+
+fn user_list(ids: impl Iterator<Item = u32>) -> MockResponse {
+    let users: Vec<_> = ids
+        .map(|id| {
+            format!(
+                r#"{{"avatar_url":"","country_code":"AU","default_group":"default","id":{id},"is_active":true,"is_bot":false,"is_deleted":false,"is_online":false,"is_supporter":false,"pm_friends_only":false,"username":"user{id}"}}"#
+            )
+        })
+        .collect();
+
+    MockResponse::json(format!(r#"{{"users":[{}]}}"#, users.join(",")))
+}
+
+#[tokio::test]
+async fn users_bulk() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    // Chunks are sent concurrently so responses may be served to any of them
+    server.mock_once("GET", "users", user_list((1..=40).filter(|&id| id != 7)));
+    server.mock_once("GET", "users", user_list(41..=80));
+    server.mock_once(
+        "GET",
+        "users",
+        user_list((81..=120).filter(|&id| id != 110)),
+    );
+
+    let ids = (1..=120).rev().chain([5, 120]);
+    let lookup = osu.users_bulk(ids).await?;
+
+    let expected: Vec<_> = (1..=120).rev().filter(|&id| id != 7 && id != 110).collect();
+    let user_ids: Vec<_> = lookup.items.iter().map(|user| user.user_id).collect();
+    assert_eq!(user_ids, expected);
+    assert_eq!(lookup.missing, [110, 7]);
+
+    let received = server.received();
+    assert_eq!(received.len(), 4);
+
+    for req in &received[1..] {
+        let ids = req
+            .query
+            .as_deref()
+            .unwrap_or_default()
+            .matches("ids")
+            .count();
+        assert!(ids <= 50, "{ids}");
+    }
+
+    Ok(())
+}
+
+fn kudosu_list(ids: &[u32]) -> MockResponse {
+    let entries: Vec<_> = ids
+        .iter()
+        .map(|id| {
+            format!(
+                r#"{{"id":{id},"action":"vote.give","amount":1,"model":"forum_post","created_at":"2024-01-01T00:00:00Z","post":{{"title":"post"}}}}"#
+            )
+        })
+        .collect();
+
+    MockResponse::json(format!("[{}]", entries.join(",")))
+}
+
+#[tokio::test]
+async fn offset_paginator() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    let route = "users/2/kudosu";
+    server.mock_once("GET", route, kudosu_list(&[9, 8]));
+    // A new entry shifted the previous ones back by one
+    server.mock_once("GET", route, kudosu_list(&[8, 7]));
+    server.mock_once("GET", route, kudosu_list(&[6]));
+    server.mock_once("GET", route, kudosu_list(&[]));
+
+    let ids: Vec<_> = osu
+        .kudosu(2)
+        .limit(2)
+        .offset(1)
+        .all()
+        .map(|res| res.map(|entry| entry.id))
+        .try_collect()
+        .await?;
+
+    assert_eq!(ids, [9, 8, 7, 6]);
+
+    // The osu!api may return fewer items than requested so only an empty
+    // page ends the stream
+    let received = server.received();
+    assert_eq!(received.len(), 5);
+    assert_eq!(received[1].query.as_deref(), Some("limit=2&offset=1"));
+    assert_eq!(received[2].query.as_deref(), Some("limit=2&offset=3"));
+    assert_eq!(received[3].query.as_deref(), Some("limit=2&offset=5"));
+    assert_eq!(received[4].query.as_deref(), Some("limit=2&offset=6"));
+
+    // Limits are capped to the endpoint's maximum
+    server.mock_once("GET", route, kudosu_list(&[]));
+    let entries: Vec<_> = osu.kudosu(2).limit(500).all().try_collect().await?;
+    assert!(entries.is_empty());
+    assert_eq!(
+        server.received()[5].query.as_deref(),
+        Some("limit=100&offset=0")
+    );
+
+    Ok(())
+}
+
+fn match_event(id: u64, kind: &str, user_id: u32) -> String {
+    format!(
+        r#"{{"id":{id},"timestamp":"2024-01-01T00:00:00Z","user_id":{user_id},"detail":{{"type":"{kind}"}}}}"#
+    )
+}
+
+fn match_game_event(id: u64, game_id: u64, finished: bool) -> String {
+    let end_time = if finished {
+        r#""2024-01-01T00:05:00Z""#
+    } else {
+        "null"
+    };
+
+    format!(
+        r#"{{"id":{id},"timestamp":"2024-01-01T00:00:00Z","user_id":null,"detail":{{"type":"other","text":"watched"}},"game":{{"id":{game_id},"start_time":"2024-01-01T00:00:00Z","end_time":{end_time},"mode":"osu","scoring_type":"score","team_type":"head-to-head","mods":[],"beatmap":null,"scores":[]}}}}"#
+    )
+}
+
+fn osu_match(events: &[String], users: &[u32], latest_event_id: u64, ended: bool) -> MockResponse {
+    let end_time = if ended {
+        r#""2024-01-01T01:00:00Z""#
+    } else {
+        "null"
+    };
+
+    let users: Vec<_> = users
+        .iter()
+        .map(|id| {
+            format!(
+                r#"{{"avatar_url":"","country_code":"AU","default_group":"default","id":{id},"is_active":true,"is_bot":false,"is_deleted":false,"is_online":false,"is_supporter":false,"pm_friends_only":false,"username":"user{id}"}}"#
+            )
+        })
+        .collect();
+
+    MockResponse::json(format!(
+        r#"{{"match":{{"id":1,"name":"watched","start_time":"2024-01-01T00:00:00Z","end_time":{end_time}}},"events":[{}],"users":[{}],"first_event_id":1,"latest_event_id":{latest_event_id},"current_game_id":null}}"#,
+        events.join(","),
+        users.join(",")
+    ))
+}
+
+#[tokio::test]
+async fn match_watcher() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    let initial = [
+        match_event(1, "match-created", 2),
+        match_event(2, "player-joined", 2),
+        match_game_event(3, 10, false),
+    ];
+
+    server.mock_once("GET", "matches/1", osu_match(&initial, &[], 3, false));
+
+    // Still playing, someone joined
+    let poll = [
+        match_game_event(3, 10, false),
+        match_event(4, "player-joined", 3),
+    ];
+    server.mock_once("GET", "matches/1", osu_match(&poll, &[], 4, false));
+
+    // Game finished, host changed, someone left
+    let poll = [
+        match_game_event(3, 10, true),
+        match_event(4, "player-joined", 3),
+        match_event(5, "host-changed", 3),
+        match_event(6, "player-left", 2),
+    ];
+    server.mock_once("GET", "matches/1", osu_match(&poll, &[], 6, false));
+
+    let poll = [match_event(7, "match-disbanded", 3)];
+    server.mock_once("GET", "matches/1", osu_match(&poll, &[], 7, true));
+
+    let mut watcher = osu
+        .osu_match(1)
+        .await?
+        .watch(&osu)
+        .interval(Duration::from_millis(1));
+
+    let mut updates = Vec::new();
+
+    while let Some(update) = watcher.next().await {
+        updates.push(update?);
+    }
+
+    let [joined, finished, host, left, disbanded] = updates.as_slice() else {
+        panic!("unexpected updates: {updates:#?}");
+    };
+
+    assert_eq!(joined, &MatchUpdate::Joined { user_id: 3 });
+    assert!(
+        matches!(finished, MatchUpdate::GameFinished { game } if game.game_id == 10 && game.end_time.is_some())
+    );
+    assert_eq!(host, &MatchUpdate::HostChanged { user_id: 3 });
+    assert_eq!(left, &MatchUpdate::Left { user_id: 2 });
+    assert_eq!(disbanded, &MatchUpdate::Disbanded);
+
+    let state = watcher.into_match();
+    assert_eq!(state.events.len(), 7);
+    assert!(state.end_time.is_some());
+
+    // Polls resume before the game that was still in progress
+    let received = server.received();
+    assert_eq!(received.len(), 5);
+    assert_eq!(received[2].query.as_deref(), Some("after=2&limit=100"));
+    assert_eq!(received[3].query.as_deref(), Some("after=2&limit=100"));
+    assert_eq!(received[4].query.as_deref(), Some("after=6&limit=100"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn match_watcher_not_found() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    let initial = [match_event(1, "match-created", 2)];
+    server.mock_once("GET", "matches/1", osu_match(&initial, &[], 1, false));
+    server.mock_once(
+        "GET",
+        "matches/1",
+        MockResponse::status(StatusCode::NOT_FOUND),
+    );
+
+    let mut watcher = osu
+        .osu_match(1)
+        .await?
+        .watch(&osu)
+        .interval(Duration::from_millis(1));
+
+    let err = watcher.next().await.expect("missing error").unwrap_err();
+    assert!(matches!(err, OsuError::NotFound), "{err:?}");
+    assert!(watcher.next().await.is_none());
+    assert_eq!(server.received().len(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn osu_match_full() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    let latest = [
+        match_event(4, "player-joined", 4),
+        match_event(5, "host-changed", 4),
+        match_game_event(6, 10, false),
+    ];
+    server.mock_once("GET", "matches/1", osu_match(&latest, &[4], 6, false));
+
+    let previous = [
+        match_event(2, "player-joined", 2),
+        match_event(3, "player-joined", 3),
+        match_event(4, "player-joined", 4),
+    ];
+    server.mock_once("GET", "matches/1", osu_match(&previous, &[2, 3], 6, false));
+
+    let previous = [match_event(1, "match-created", 2)];
+    server.mock_once("GET", "matches/1", osu_match(&previous, &[2], 7, false));
+
+    // A new event happened while walking backwards
+    let next = [
+        match_game_event(6, 10, true),
+        match_event(7, "player-left", 3),
+    ];
+    server.mock_once("GET", "matches/1", osu_match(&next, &[3], 7, false));
+
+    let full = osu.osu_match_full(1).await?;
+
+    let event_ids: Vec<_> = full.events.iter().map(|event| event.event_id()).collect();
+    assert_eq!(event_ids, [1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(full.latest_event_id, 7);
+
+    let mut user_ids: Vec<_> = full.users.keys().copied().collect();
+    user_ids.sort_unstable();
+    assert_eq!(user_ids, [2, 3, 4]);
+
+    // The game was replaced by its more recent version
+    let finished = full
+        .events
+        .iter()
+        .any(|event| matches!(event, MatchEvent::Game { game, .. } if game.end_time.is_some()));
+    assert!(finished);
+
+    let received = server.received();
+    assert_eq!(received.len(), 5);
+    assert_eq!(received[1].query.as_deref(), Some("limit=100"));
+    assert_eq!(received[2].query.as_deref(), Some("before=4&limit=100"));
+    assert_eq!(received[3].query.as_deref(), Some("before=2&limit=100"));
+    assert_eq!(received[4].query.as_deref(), Some("after=6&limit=100"));
+
+    Ok(())
+}
+
+fn processed_scores(ids: &[u64], cursor: &str) -> MockResponse {
+    let scores: Vec<_> = ids
+        .iter()
+        .map(|id| {
+            format!(
+                r#"{{"id":{id},"mods":[],"statistics":{{}},"rank":"A","type":"solo_score","user_id":2,"accuracy":0.95,"ended_at":"2024-01-01T00:00:00Z","max_combo":100,"passed":true,"ruleset_id":0,"total_score":1000,"replay":false,"current_user_attributes":{{"pin":null}}}}"#
+            )
+        })
+        .collect();
+
+    MockResponse::json(format!(
+        r#"{{"scores":[{}],"cursor_string":"{cursor}"}}"#,
+        scores.join(",")
+    ))
+}
+
+#[tokio::test]
+async fn score_feed() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    server.mock_once("GET", "scores", processed_scores(&[1, 2], "a"));
+    server.mock_once("GET", "scores", processed_scores(&[], "a"));
+    // The second score was already yielded
+    server.mock_once("GET", "scores", processed_scores(&[2, 3, 4], "b"));
+
+    let mut feed = osu.score_feed().interval(Duration::from_millis(1));
+
+    let first = feed.next().await.unwrap()?;
+    let ids: Vec<_> = first.scores.iter().map(|score| score.id).collect();
+    assert_eq!(ids, [1, 2]);
+    assert_eq!(first.checkpoint.last_score_id(), 2);
+
+    // Empty polls are skipped
+    let second = feed.next().await.unwrap()?;
+    let ids: Vec<_> = second.scores.iter().map(|score| score.id).collect();
+    assert_eq!(ids, [3, 4]);
+    assert_eq!(feed.checkpoint(), Some(&second.checkpoint));
+
+    // Resume from the stored checkpoint of the first batch
+    let stored = serde_json::to_string(&first.checkpoint)?;
+    let checkpoint = serde_json::from_str(&stored)?;
+
+    server.mock_once("GET", "scores", processed_scores(&[2, 3], "b"));
+
+    let mut feed = osu
+        .score_feed()
+        .mode(GameMode::Taiko)
+        .resume(checkpoint)
+        .interval(Duration::from_millis(1));
+
+    let resumed = feed.next().await.unwrap()?;
+    let ids: Vec<_> = resumed.scores.iter().map(|score| score.id).collect();
+    assert_eq!(ids, [3]);
+
+    let received = server.received();
+    assert_eq!(received.len(), 5);
+    assert_eq!(received[1].query.as_deref(), Some(""));
+    assert_eq!(received[2].query.as_deref(), Some("cursor_string=a"));
+    assert_eq!(received[3].query.as_deref(), Some("cursor_string=a"));
+    assert_eq!(
+        received[4].query.as_deref(),
+        Some("ruleset=taiko&cursor_string=a")
+    );
+
+    Ok(())
+}
+
+fn events(events: &[(u32, bool)], cursor: Option<&str>) -> MockResponse {
+    let events: Vec<_> = events
+        .iter()
+        .map(|&(id, rank)| {
+            if rank {
+                format!(
+                    r#"{{"created_at":"2024-01-01T00:00:00Z","id":{id},"type":"rank","scoreRank":"S","rank":1,"mode":"osu","beatmap":{{"title":"map","url":"/b/1"}},"user":{{"username":"user{id}","url":"/u/{id}"}}}}"#
+                )
+            } else {
+                format!(
+                    r#"{{"created_at":"2024-01-01T00:00:00Z","id":{id},"type":"beatmapsetDelete","beatmapset":{{"title":"mapset","url":"/s/1"}}}}"#
+                )
+            }
+        })
+        .collect();
+
+    let cursor = cursor.map_or_else(|| "null".to_owned(), |cursor| format!("\"{cursor}\""));
+
+    MockResponse::json(format!(
+        r#"{{"events":[{}],"cursor_string":{cursor}}}"#,
+        events.join(",")
+    ))
+}
+
+#[tokio::test]
+async fn event_watcher() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    // Only sets the baseline
+    server.mock_once("GET", "events", events(&[(3, true), (2, true)], Some("a")));
+    // More new events than fit on one page
+    server.mock_once("GET", "events", events(&[(6, true), (5, true)], Some("b")));
+    server.mock_once("GET", "events", events(&[(4, false), (3, true)], Some("c")));
+
+    let mut watcher = osu
+        .event_watcher()
+        .interval(Duration::from_millis(1))
+        .filter(|event| matches!(event, EventType::Rank { rank: 1, .. }));
+
+    let first = watcher.next().await.unwrap()?;
+    let second = watcher.next().await.unwrap()?;
+
+    assert_eq!(first.event_id, 5);
+    assert_eq!(second.event_id, 6);
+    assert_eq!(watcher.last_event_id(), Some(6));
+
+    let received = server.received();
+    assert_eq!(received.len(), 4);
+    assert_eq!(received[1].query.as_deref(), Some("sort=id_desc"));
+    assert_eq!(received[2].query.as_deref(), Some("sort=id_desc"));
+    assert_eq!(
+        received[3].query.as_deref(),
+        Some("sort=id_desc&cursor_string=b")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn batch() -> Result<()> {
+    use rosu_v2::prelude::{KudosuHistory, WikiPage};
+
+    #[derive(Debug)]
+    enum Output {
+        Kudosu(Vec<KudosuHistory>),
+        Wiki(WikiPage),
+    }
+
+    impl From<Vec<KudosuHistory>> for Output {
+        fn from(kudosu: Vec<KudosuHistory>) -> Self {
+            Self::Kudosu(kudosu)
+        }
+    }
+
+    impl From<WikiPage> for Output {
+        fn from(page: WikiPage) -> Self {
+            Self::Wiki(page)
+        }
+    }
+
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    server.mock("GET", "users/2/kudosu", kudosu_list(&[1, 2]));
+
+    let results = osu
+        .batch::<Output>()
+        .concurrency(2)
+        .push(osu.wiki("en").page("Missing"))
+        .push(osu.kudosu(2))
+        .push(osu.wiki("en").page("Main_page"))
+        .await;
+
+    let [missing, kudosu, page] = results.as_slice() else {
+        panic!("unexpected results: {results:#?}");
+    };
+
+    assert!(matches!(missing, Err(OsuError::NotFound)), "{missing:?}");
+    assert!(matches!(kudosu, Ok(Output::Kudosu(entries)) if entries.len() == 2));
+    assert!(matches!(page, Ok(Output::Wiki(page)) if page.title == "Main page"));
+
+    // Cancelling stops all remaining requests
+    let mut batch = osu.batch().concurrency(1);
+    batch.extend((0..5).map(|_| osu.kudosu(2)));
+
+    let mut stream = batch.unordered();
+    let (idx, res) = stream.next().await.unwrap();
+    assert_eq!(idx, 0);
+    assert_eq!(res?.len(), 2);
+    assert_eq!(stream.remaining(), 4);
+
+    stream.cancel();
+    assert!(stream.next().await.is_none());
+
+    let received = server.received();
+    assert_eq!(received.len(), 5);
+
+    // Requests that wait for their turn must not block the running ones
+    server.mock("GET", "users/3/kudosu", kudosu_list(&[1]));
+
+    let batch = osu
+        .batch::<Output>()
+        .concurrency(1)
+        .push(osu.kudosu("peppy"))
+        .push(osu.kudosu("peppy"));
+
+    let results = tokio::time::timeout(Duration::from_secs(5), batch)
+        .await
+        .expect("batch must not hang");
+
+    assert!(results.iter().all(Result::is_ok), "{results:#?}");
+
+    // The second request is only created once the first one cached the name
+    #[cfg(feature = "cache")]
+    assert_eq!(server.received().len(), received.len() + 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn json_body_escaping() -> Result<()> {
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    let message = r#"{"channel_id":5,"content":"","is_action":false,"message_id":9,"sender_id":2,"timestamp":"2024-01-01T00:00:00Z","type":"plain","uuid":null}"#;
+    server.mock(
+        "POST",
+        "chat/channels/5/messages",
+        MockResponse::json(message),
+    );
+
+    osu.send_chat_message(5, "say \"hi\"\n\\o/").await?;
+
+    assert_eq!(
+        server.received()[1].body.as_ref(),
+        br#"{"message":"say \"hi\"\n\\o/","is_action":false}"#
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn chat_messages_not_resent() -> Result<()> {
+    let server = server().await?;
+
+    let policy = RetryPolicy::new().initial_backoff(Duration::from_millis(10));
+    let osu = server.osu_builder().retry_policy(policy).build().await?;
+
+    let route = "chat/channels/5/messages";
+    let unavailable = MockResponse::status(StatusCode::SERVICE_UNAVAILABLE);
+    server.mock_once("POST", route, unavailable);
+
+    let err = osu.send_chat_message(5, "hi").await.unwrap_err();
+    assert!(
+        matches!(err, OsuError::ServiceUnavailable { .. }),
+        "{err:?}"
+    );
+
+    let posts = server
+        .received()
+        .iter()
+        .filter(|req| req.path.ends_with(route))
+        .count();
+
+    assert_eq!(posts, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn chat() -> Result<()> {
+    use rosu_v2::prelude::{ChatChannelType, ChatMessageType};
+
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    let channel = r##"{"channel_id":5,"name":"#osu","description":"General discussion","icon":null,"type":"PUBLIC","message_length_limit":450,"moderated":false,"uuid":null}"##;
+    let sender = r#"{"avatar_url":"","country_code":"AU","default_group":"default","id":2,"is_active":true,"is_bot":false,"is_deleted":false,"is_online":false,"is_supporter":false,"pm_friends_only":false,"username":"peppy"}"#;
+    let message = format!(
+        r#"{{"channel_id":5,"content":"hi \"there\"","is_action":true,"message_id":9,"sender_id":2,"timestamp":"2024-01-01T00:00:00Z","type":"action","uuid":null,"sender":{sender}}}"#
+    );
+
+    server.mock(
+        "GET",
+        "chat/channels",
+        MockResponse::json(format!("[{channel}]")),
+    );
+    server.mock(
+        "PUT",
+        "chat/channels/5/users/2",
+        MockResponse::json(channel),
+    );
+    server.mock(
+        "POST",
+        "chat/channels/5/messages",
+        MockResponse::json(message.clone()),
+    );
+    server.mock(
+        "GET",
+        "chat/channels/5/messages",
+        MockResponse::json(format!("[{message}]")),
+    );
+    server.mock(
+        "DELETE",
+        "chat/channels/5/users/2",
+        MockResponse::status(StatusCode::NO_CONTENT),
+    );
+    server.mock(
+        "POST",
+        "chat/ack",
+        MockResponse::json(r#"{"silences":[{"id":1,"user_id":3}]}"#),
+    );
+
+    let channels = osu.chat_channels().await?;
+    assert_eq!(channels.len(), 1);
+    assert_eq!(channels[0].kind, ChatChannelType::Public);
+
+    let joined = osu.join_chat_channel(5, 2).await?;
+    assert_eq!(joined.name, "#osu");
+
+    let sent = osu.send_chat_message(5, "hi").action(true).await?;
+    assert_eq!(sent.kind, ChatMessageType::Action);
+    assert_eq!(sent.sender.map(|user| user.username), Some("peppy".into()));
+
+    let messages = osu.chat_messages(5).since(8).limit(10).await?;
+    assert_eq!(messages[0].content, r#"hi "there""#);
+
+    let ack = osu.chat_ack().since(9).await?;
+    assert_eq!(ack.silences[0].user_id, 3);
+
+    osu.leave_chat_channel(5, 2).await?;
+
+    let received = server.received();
+    assert_eq!(received.len(), 7);
+    assert_eq!(received[2].method, "PUT");
+    assert_eq!(received[3].method, "POST");
+    assert_eq!(
+        received[3].body.as_ref(),
+        br#"{"message":"hi","is_action":true}"#
+    );
+    assert_eq!(received[4].query.as_deref(), Some("limit=10&since=8"));
+    assert_eq!(received[5].query.as_deref(), Some("since=9"));
+    assert_eq!(received[6].method, "DELETE");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rooms() -> Result<()> {
+    use rosu_v2::prelude::{PlaylistScoresSort, RoomCategory, RoomFilter};
+
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    let item = r#"{"id":2,"room_id":1,"beatmap_id":3,"ruleset_id":0,"allowed_mods":[],"required_mods":[{"acronym":"HD"}],"expired":false,"owner_id":4,"playlist_order":null,"played_at":null}"#;
+    let room = format!(
+        r#"{{"id":1,"name":"Daily Challenge","category":"daily_challenge","type":"playlists","user_id":4,"starts_at":"2024-01-01T00:00:00Z","ends_at":null,"max_attempts":null,"participant_count":10,"channel_id":6,"active":true,"has_password":false,"queue_mode":"host_only","auto_skip":false,"current_playlist_item":{item},"playlist":[{item}]}}"#
+    );
+
+    server.mock("GET", "rooms", MockResponse::json(format!("[{room}]")));
+    server.mock("GET", "rooms/1", MockResponse::json(room));
+    server.mock(
+        "GET",
+        "rooms/1/leaderboard",
+        MockResponse::json(
+            r#"{"leaderboard":[{"accuracy":0.95,"attempts":3,"completed":1,"pp":0,"room_id":1,"total_score":1000,"user_id":2}],"user_score":null}"#,
+        ),
+    );
+    server.mock_once(
+        "GET",
+        "rooms/1/playlist/2/scores",
+        playlist_scores(&[1, 2], Some("a")),
+    );
+    server.mock_once(
+        "GET",
+        "rooms/1/playlist/2/scores",
+        playlist_scores(&[3], None),
+    );
+
+    let rooms = osu.rooms().filter(RoomFilter::Ended).limit(5).await?;
+    assert_eq!(rooms[0].category, RoomCategory::DailyChallenge);
+
+    let room = osu.room(1).await?;
+    let item = &room.playlist[0];
+    assert_eq!(item.required_mods.to_string(), "HD");
+    assert!(item.allowed_mods.is_empty());
+
+    let leaderboard = osu.room_leaderboard(1).limit(10).await?;
+    assert!((leaderboard.leaderboard[0].accuracy - 95.0).abs() < f32::EPSILON);
+
+    let scores = osu
+        .playlist_scores(1, 2)
+        .limit(2)
+        .sort(PlaylistScoresSort::ScoreAscending)
+        .await?;
+    assert!(scores.has_more());
+
+    let ids: Vec<_> = scores
+        .into_stream(&osu)
+        .map_ok(|score| score.id)
+        .try_collect()
+        .await?;
+    assert_eq!(ids, [1, 2, 3]);
+
+    let received = server.received();
+    assert_eq!(received.len(), 6);
+    assert_eq!(received[1].query.as_deref(), Some("mode=ended&limit=5"));
+    assert_eq!(received[3].query.as_deref(), Some("limit=10"));
+    assert_eq!(received[4].query.as_deref(), Some("limit=2&sort=score_asc"));
+    assert_eq!(
+        received[5].query.as_deref(),
+        Some("limit=2&sort=score_asc&cursor_string=a")
+    );
+
+    Ok(())
+}
+
+fn playlist_scores(ids: &[u64], cursor: Option<&str>) -> MockResponse {
+    let scores: Vec<_> = ids
+        .iter()
+        .map(|id| {
+            format!(
+                r#"{{"id":{id},"mods":[],"statistics":{{}},"rank":"A","type":"solo_score","user_id":2,"accuracy":0.95,"ended_at":"2024-01-01T00:00:00Z","max_combo":100,"passed":true,"ruleset_id":0,"total_score":1000,"replay":false,"current_user_attributes":{{"pin":null}},"playlist_item_id":2,"room_id":1,"solo_score_id":null}}"#
+            )
+        })
+        .collect();
+
+    let cursor = cursor.map_or_else(|| "null".to_owned(), |cursor| format!(r#""{cursor}""#));
+
+    MockResponse::json(format!(
+        r#"{{"scores":[{}],"total":3,"cursor_string":{cursor}}}"#,
+        scores.join(",")
+    ))
+}
+
+#[tokio::test]
+async fn beatmap_packs() -> Result<()> {
+    use rosu_v2::prelude::BeatmapPackType;
+
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    let pack = |tag: &str| {
+        format!(
+            r#"{{"author":"peppy","date":"2024-01-01T00:00:00Z","name":"Pack {tag}","no_diff_reduction":false,"ruleset_id":null,"tag":"{tag}","url":"https://packs.ppy.sh/{tag}.zip"}}"#
+        )
+    };
+
+    let covers = r#"{"cover":"","cover@2x":"","card":"","card@2x":"","list":"","list@2x":"","slimcover":"","slimcover@2x":""}"#;
+    let mapset = format!(
+        r#"{{"artist":"Artist","covers":{covers},"creator":"peppy","user_id":2,"favourite_count":0,"id":3,"nsfw":false,"play_count":0,"preview_url":"","source":"","status":"ranked","title":"Title","video":false}}"#
+    );
+    let detailed = format!(
+        r#"{{"author":"peppy","date":"2024-01-01T00:00:00Z","name":"Pack T1","no_diff_reduction":true,"ruleset_id":0,"tag":"T1","url":"","beatmapsets":[{mapset}],"user_completion_data":{{"completed":false,"beatmapset_ids":[3]}}}}"#
+    );
+
+    server.mock_once(
+        "GET",
+        "beatmaps/packs",
+        MockResponse::json(format!(
+            r#"{{"beatmap_packs":[{},{}],"cursor_string":"a"}}"#,
+            pack("T2"),
+            pack("T1")
+        )),
+    );
+    server.mock_once(
+        "GET",
+        "beatmaps/packs",
+        MockResponse::json(format!(
+            r#"{{"beatmap_packs":[{}],"cursor_string":null}}"#,
+            pack("T0")
+        )),
+    );
+    server.mock("GET", "beatmaps/packs/T1", MockResponse::json(detailed));
+
+    let packs = osu
+        .beatmap_packs()
+        .kind(BeatmapPackType::Tournament)
+        .await?;
+    assert!(packs.has_more());
+
+    let tags: Vec<_> = packs
+        .into_stream(&osu)
+        .map_ok(|pack| pack.tag)
+        .try_collect()
+        .await?;
+    assert_eq!(tags, ["T2", "T1", "T0"]);
+
+    let pack = osu.beatmap_pack("T1").await?;
+    assert_eq!(pack.mode, Some(GameMode::Osu));
+    assert_eq!(pack.mapsets.map(|mapsets| mapsets.len()), Some(1));
+
+    let completion = pack.user_completion_data.unwrap();
+    assert!(!completion.completed);
+    assert_eq!(completion.completed_mapset_ids, [3]);
+
+    let received = server.received();
+    assert_eq!(received.len(), 4);
+    assert_eq!(received[1].query.as_deref(), Some("type=tournament"));
+    assert_eq!(
+        received[2].query.as_deref(),
+        Some("type=tournament&cursor_string=a")
+    );
+    assert_eq!(received[3].query.as_deref(), Some("legacy_only=false"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn beatmapset_discussions() -> Result<()> {
+    use rosu_v2::prelude::{BeatmapsetDiscussionMessageType, BeatmapsetPostType};
+
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    let post = |id: u64| {
+        format!(
+            r#"{{"id":{id},"beatmapset_discussion_id":{id},"user_id":2,"last_editor_id":null,"deleted_by_id":null,"system":false,"message":"msg","created_at":"2024-01-01T00:00:00Z","updated_at":null,"deleted_at":null}}"#
+        )
+    };
+    let discussion = |id: u64| {
+        format!(
+            r#"{{"id":{id},"beatmapset_id":3,"beatmap_id":null,"user_id":2,"deleted_by_id":null,"message_type":"problem","parent_id":null,"timestamp":null,"resolved":false,"can_be_resolved":true,"can_grant_kudosu":true,"created_at":"2024-01-01T00:00:00Z","updated_at":null,"deleted_at":null,"last_post_at":"2024-01-01T00:00:00Z","kudosu_denied":false,"starting_post":{}}}"#,
+            post(id)
+        )
+    };
+    let discussions = |ids: &[u64], cursor: &str| {
+        let discussions: Vec<_> = ids.iter().map(|&id| discussion(id)).collect();
+
+        MockResponse::json(format!(
+            r#"{{"beatmaps":[],"discussions":[{}],"included_discussions":[],"reviews_config":{{"max_blocks":10}},"users":[],"cursor_string":{cursor}}}"#,
+            discussions.join(",")
+        ))
+    };
+
+    server.mock_once(
+        "GET",
+        "beatmapsets/discussions",
+        discussions(&[5, 4], r#""a""#),
+    );
+    server.mock_once("GET", "beatmapsets/discussions", discussions(&[3], "null"));
+    server.mock(
+        "GET",
+        "beatmapsets/discussions/posts",
+        MockResponse::json(format!(
+            r#"{{"beatmapsets":[],"posts":[{}],"users":[],"cursor_string":null}}"#,
+            post(4)
+        )),
+    );
+    server.mock(
+        "GET",
+        "beatmapsets/discussions/votes",
+        MockResponse::json(format!(
+            r#"{{"discussions":[{}],"votes":[{{"id":1,"beatmapset_discussion_id":4,"user_id":6,"score":1,"created_at":"2024-01-01T00:00:00Z","updated_at":"2024-01-01T00:00:00Z"}}],"users":[],"cursor_string":null}}"#,
+            discussion(4)
+        )),
+    );
+
+    let first = osu
+        .beatmapset_discussions()
+        .mapset_id(3)
+        .message_types([
+            BeatmapsetDiscussionMessageType::Problem,
+            BeatmapsetDiscussionMessageType::Suggestion,
+        ])
+        .only_unresolved(true)
+        .await?;
+    assert!(first.has_more());
+
+    let ids: Vec<_> = first
+        .into_stream(&osu)
+        .map_ok(|discussion| discussion.discussion_id)
+        .try_collect()
+        .await?;
+    assert_eq!(ids, [5, 4, 3]);
+
+    let posts = osu
+        .beatmapset_discussion_posts()
+        .discussion_id(4)
+        .types([BeatmapsetPostType::First, BeatmapsetPostType::Reply])
+        .await?;
+    assert_eq!(posts.posts[0].message, "msg");
+    assert!(!posts.has_more());
+
+    let votes = osu
+        .beatmapset_discussion_votes()
+        .receiver_id(2)
+        .upvotes(true)
+        .await?;
+    assert_eq!(votes.votes[0].score, 1);
+    assert_eq!(votes.discussions[0].discussion_id, 4);
+
+    let received = server.received();
+    assert_eq!(received.len(), 5);
+
+    let query = "beatmapset_id=3&message_types%5B%5D=problem&message_types%5B%5D=suggestion&only_unresolved=true";
+    assert_eq!(received[1].query.as_deref(), Some(query));
+    assert_eq!(
+        received[2].query.as_deref(),
+        Some(format!("{query}&cursor_string=a").as_str())
+    );
+    assert_eq!(
+        received[3].query.as_deref(),
+        Some("beatmapset_discussion_id=4&types%5B%5D=first&types%5B%5D=reply")
+    );
+    assert_eq!(received[4].query.as_deref(), Some("receiver=2&score=1"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn forum_writes_and_listings() -> Result<()> {
+    use rosu_v2::prelude::{ForumTopicPoll, ForumTopicSort};
+
+    let server = server().await?;
+    let osu = server.osu_builder().build().await?;
+
+    let topic = |id: u64| {
+        format!(
+            r#"{{"created_at":"2024-01-01T00:00:00Z","first_post_id":1,"forum_id":4,"is_locked":false,"type":"normal","last_post_id":5,"post_count":5,"title":"Results","id":{id},"user_id":2}}"#
+        )
+    };
+    let post = r#"{"created_at":"2024-01-01T00:00:00Z","forum_id":4,"id":1,"topic_id":7,"user_id":2,"body":{"html":"","raw":"text"}}"#;
+
+    server.mock(
+        "GET",
+        "forums",
+        MockResponse::json(
+            r#"{"forums":[{"id":1,"name":"General","description":"","subforums":[{"id":4,"name":"Tournaments","description":""}]}]}"#,
+        ),
+    );
+    server.mock(
+        "GET",
+        "forums/4",
+        MockResponse::json(format!(
+            r#"{{"forum":{{"id":4,"name":"Tournaments","description":""}},"topics":[{}],"pinned_topics":[]}}"#,
+            topic(7)
+        )),
+    );
+    server.mock_once(
+        "GET",
+        "forums/topics",
+        MockResponse::json(format!(
+            r#"{{"topics":[{}],"cursor_string":"a"}}"#,
+            topic(8)
+        )),
+    );
+    server.mock_once(
+        "GET",
+        "forums/topics",
+        MockResponse::json(format!(
+            r#"{{"topics":[{}],"cursor_string":null}}"#,
+            topic(7)
+        )),
+    );
+    server.mock("GET", "forums/topics/7", forum_posts(&[1], "id_asc", None));
+    server.mock(
+        "POST",
+        "forums/topics",
+        MockResponse::json(format!(r#"{{"topic":{},"post":{post}}}"#, topic(7))),
+    );
+    server.mock("POST", "forums/topics/7/reply", MockResponse::json(post));
+    server.mock("PUT", "forums/posts/1", MockResponse::json(post));
+
+    let forums = osu.forums().await?;
+    assert_eq!(forums[0].subforums[0].forum_id, 4);
+
+    let forum = osu.forum(4).await?;
+    assert_eq!(forum.topics[0].topic_id, 7);
+
+    let ids: Vec<_> = osu
+        .forum_topics()
+        .forum_id(4)
+        .sort(ForumTopicSort::Old)
+        .await?
+        .into_stream(&osu)
+        .map_ok(|topic| topic.topic_id)
+        .try_collect()
+        .await?;
+    assert_eq!(ids, [8, 7]);
+
+    let topic = osu.forum_topic(7).await?;
+    assert_eq!(topic.title, "topic");
+
+    let poll = ForumTopicPoll::new("Winner?", ["A", "B"]);
+    let created = osu
+        .create_forum_topic(4, "Results", "text")
+        .poll(poll)
+        .await?;
+    assert_eq!(created.post.raw, "text");
+
+    osu.reply_forum_topic(7, "reply").await?;
+    osu.edit_forum_post(1, "edit").await?;
+
+    let received = server.received();
+    assert_eq!(received.len(), 9);
+    assert_eq!(received[3].query.as_deref(), Some("forum_id=4&sort=old"));
+    assert_eq!(
+        received[4].query.as_deref(),
+        Some("forum_id=4&sort=old&cursor_string=a")
+    );
+    assert_eq!(received[5].query.as_deref(), Some("limit=1"));
+    assert_eq!(
+        received[6].body.as_ref(),
+        br#"{"body":"text","forum_id":"4","title":"Results","with_poll":true,"forum_topic_poll":{"hide_results":false,"length_days":"0","max_options":"1","options":"A\nB","title":"Winner?","vote_change":false}}"#
+    );
+    assert_eq!(received[7].body.as_ref(), br#"{"body":"reply"}"#);
+    assert_eq!(received[8].method, "PUT");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn forum_writes_not_resent() -> Result<()> {
+    let server = server().await?;
+
+    let policy = RetryPolicy::new().initial_backoff(Duration::from_millis(10));
+
+    let osu = server
+        .osu_builder()
+        .retry_policy(policy)
+        .timeout(Duration::from_millis(100))
+        .build()
+        .await?;
+
+    let unavailable = MockResponse::status(StatusCode::SERVICE_UNAVAILABLE);
+    let delayed = MockResponse::status(StatusCode::OK).with_delay(Duration::from_secs(1));
+
+    server.mock_once("POST", "forums/topics", unavailable.clone());
+    server.mock_once("POST", "forums/topics/7/reply", unavailable);
+    server.mock_once("PUT", "forums/posts/1", delayed);
+
+    let err = osu
+        .create_forum_topic(4, "Results", "text")
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, OsuError::ServiceUnavailable { .. }),
+        "{err:?}"
+    );
+
+    let err = osu.reply_forum_topic(7, "reply").await.unwrap_err();
+    assert!(
+        matches!(err, OsuError::ServiceUnavailable { .. }),
+        "{err:?}"
+    );
+
+    let err = osu.edit_forum_post(1, "edit").await.unwrap_err();
+    assert!(matches!(err, OsuError::RequestTimeout), "{err:?}");
+
+    let paths: Vec<_> = server
+        .received()
+        .into_iter()
+        .map(|req| req.path)
+        .filter(|path| path.starts_with("/api"))
+        .collect();
+
+    assert_eq!(
+        paths,
+        [
+            "/api/v2/forums/topics",
+            "/api/v2/forums/topics/7/reply",
+            "/api/v2/forums/posts/1",
+        ]
+    );
+
+    Ok(())
+}
@@ -324,7 +324,7 @@ async fn events() -> Result<()> {
 
     let next = osu
         .events()
-        .cursor(initial.cursor.as_deref().unwrap())
+        .cursor(initial.cursor.clone().unwrap())
         .sort(EventSort::IdDescending)
         .await?;
     println!("Next descending events: {}", next.events.len());
@@ -336,16 +336,17 @@ async fn events() -> Result<()> {
 
 #[tokio::test]
 async fn forum_posts() -> Result<()> {
-    let posts = OSU
-        .get()
-        .await?
-        .forum_posts(1265690)
-        .sort_descending()
-        .limit(10)
-        .await?;
+    let osu = OSU.get().await?;
+
+    let posts = osu.forum_posts(1265690).sort_descending().limit(10).await?;
 
     println!("Received {} posts", posts.posts.len());
 
+    let next = posts.get_next(&osu).await.unwrap()?;
+    println!("Next posts: {}", next.posts.len());
+
+    assert!(posts.posts.last().unwrap().post_id > next.posts.first().unwrap().post_id);
+
     Ok(())
 }
 
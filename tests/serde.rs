@@ -44,7 +44,7 @@ mod types {
 
     pub(super) fn get_forum_posts() -> ForumPosts {
         ForumPosts {
-            cursor: Some("my cursor".to_owned()),
+            cursor: Some(ForumPostsCursor::new("my cursor")),
             posts: vec![ForumPost {
                 created_at: get_date(),
                 deleted_at: Some(get_date()),
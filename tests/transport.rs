@@ -0,0 +1,105 @@
+extern crate rosu_v2;
+
+use std::sync::{Arc, Mutex};
+
+use bytes::Bytes;
+use eyre::Result;
+use http_body_util::{BodyExt, Full};
+use hyper::{Request, Response, StatusCode};
+use rosu_v2::{
+    transport::{Transport, TransportFuture},
+    Osu,
+};
+
+const TOKEN: &str = r#"{"access_token":"abc","expires_in":86400,"token_type":"Bearer"}"#;
+
+const WIKI_PAGE: &str = r##"{
+    "layout": "markdown_page",
+    "locale": "en",
+    "markdown": "# Main page",
+    "path": "Main_page",
+    "title": "Main page"
+}"##;
+
+/// Serves responses from memory and records the requested URIs.
+#[derive(Clone, Default)]
+struct InMemory {
+    uris: Arc<Mutex<Vec<String>>>,
+}
+
+impl Transport for InMemory {
+    fn request(&self, req: Request<Full<Bytes>>) -> TransportFuture {
+        let uri = req.uri().to_string();
+        self.uris.lock().unwrap().push(uri.clone());
+
+        let (status, body) = if uri.ends_with("/oauth/token") {
+            (StatusCode::OK, TOKEN)
+        } else if uri.contains("/wiki/en/") {
+            (StatusCode::OK, WIKI_PAGE)
+        } else {
+            (StatusCode::NOT_FOUND, r#"{"error":null}"#)
+        };
+
+        let resp = Response::builder()
+            .status(status)
+            .body(Full::new(Bytes::from_static(body.as_bytes())))
+            .unwrap()
+            .map(|body| body.map_err(|never| match never {}).boxed());
+
+        Box::pin(async move { Ok(resp) })
+    }
+}
+
+#[tokio::test]
+async fn custom_transport() -> Result<()> {
+    let transport = InMemory::default();
+
+    let osu = Osu::builder()
+        .client_id(123)
+        .client_secret("secret")
+        .transport(transport.clone())
+        .build()
+        .await?;
+
+    let page = osu.wiki("en").page("Main_page").await?;
+    assert_eq!(page.title, "Main page");
+
+    assert_eq!(
+        *transport.uris.lock().unwrap(),
+        [
+            "https://osu.ppy.sh/oauth/token",
+            "https://osu.ppy.sh/api/v2/wiki/en/Main_page",
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn base_urls() -> Result<()> {
+    let transport = InMemory::default();
+
+    let osu = Osu::builder()
+        .client_id(123)
+        .client_secret("secret")
+        .api_base_url("http://localhost:8080/api")
+        .oauth_base_url("http://localhost:8080/oauth/")
+        .transport(transport.clone())
+        .build()
+        .await?;
+
+    assert!(matches!(
+        osu.wiki("de").await,
+        Err(rosu_v2::error::OsuError::NotFound)
+    ));
+
+    assert_eq!(
+        *transport.uris.lock().unwrap(),
+        [
+            "http://localhost:8080/oauth/token",
+            "http://localhost:8080/api/wiki/de/",
+        ]
+    );
+
+    Ok(())
+}